@@ -0,0 +1,112 @@
+use anyhow::Result;
+use aoc_core::input::{stream_lines, sum_lines, LineSource};
+use aoc_core::parse::{Line, ParseError};
+use aoc_core::runner::Options;
+
+const INPUT_FILE: &str = "./data/2023/day01.txt";
+
+pub fn run(options: &Options) -> Result<()> {
+    println!(
+        "problem1 = {}",
+        problem1_solution(stream_lines(options.input_file(INPUT_FILE))?)?
+    );
+    println!(
+        "problem2 = {}",
+        problem2_solution(stream_lines(options.input_file(INPUT_FILE))?)?
+    );
+    Ok(())
+}
+
+fn problem1_solution(input: impl LineSource) -> Result<usize> {
+    sum_lines(input, |line| {
+        let first = line.text.chars().find_map(|c| c.to_digit(10));
+        let second = line.text.chars().rev().find_map(|c| c.to_digit(10));
+        match (first, second) {
+            (Some(first), Some(second)) => Ok((first * 10 + second) as usize),
+            _ => Err(line.error(line.text, "a digit").into()),
+        }
+    })
+}
+
+const NUMBERS: [[&str; 2]; 10] = [
+    ["0", "zero"],
+    ["1", "one"],
+    ["2", "two"],
+    ["3", "three"],
+    ["4", "four"],
+    ["5", "five"],
+    ["6", "six"],
+    ["7", "seven"],
+    ["8", "eight"],
+    ["9", "nine"],
+];
+
+fn problem2_solution(input: impl LineSource) -> Result<usize> {
+    sum_lines(input, |line| Ok(calibration_value(&line)?))
+}
+
+fn calibration_value(line: &Line) -> Result<usize, ParseError> {
+    let mut first_digit: Option<(usize, usize)> = None;
+    let mut last_digit: Option<(usize, usize)> = None;
+    for (n, patterns) in NUMBERS.iter().enumerate() {
+        for &pattern in patterns {
+            if let Some(i) = line.text.find(pattern) {
+                first_digit = Some(first_digit.map_or((i, n), |d| d.min((i, n))));
+            }
+            if let Some(i) = line.text.rfind(pattern) {
+                last_digit = last_digit.max(Some((i, n)));
+            }
+        }
+    }
+    match (first_digit, last_digit) {
+        (Some((_, first)), Some((_, last))) => Ok(first * 10 + last),
+        _ => Err(line.error(line.text, "a digit or spelled-out digit")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::input::memory_lines;
+
+    const INPUT: &str = "1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+
+    fn load_test_data() -> Vec<String> {
+        INPUT.lines().map(|s| s.to_owned()).collect()
+    }
+
+    #[test]
+    fn problem1() {
+        let answer = problem1_solution(memory_lines(&load_test_data())).unwrap();
+        assert_eq!(answer, 142);
+    }
+
+    #[test]
+    fn missing_digit() {
+        let input = ["1abc2", "pqrstu"];
+        let error = problem1_solution(memory_lines(&input)).unwrap_err();
+        let error = error.downcast_ref::<ParseError>().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    const SECOND_INPUT: &str = "two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+
+    fn load_second_data() -> Vec<String> {
+        SECOND_INPUT.lines().map(|s| s.to_owned()).collect()
+    }
+
+    #[test]
+    fn problem2() {
+        let answer = problem2_solution(memory_lines(&load_second_data())).unwrap();
+        assert_eq!(answer, 281);
+    }
+}
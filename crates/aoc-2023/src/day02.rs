@@ -0,0 +1,283 @@
+use anyhow::Result;
+use aoc_core::explain::Explanation;
+use aoc_core::input::{fold_lines, stream_lines, sum_lines, LineSource};
+use aoc_core::parse::{Line, ParseError};
+use aoc_core::runner::Options;
+use aoc_core::scan;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+const INPUT_FILE: &str = "./data/2023/day02.txt";
+
+pub fn run(options: &Options) -> Result<()> {
+    let params: Params = options.day_params("day02")?;
+    println!(
+        "problem1 = {}",
+        problem1_solution(stream_lines(options.input_file(INPUT_FILE))?, &params.bag)?
+    );
+    println!(
+        "problem2 = {}",
+        problem2_solution(
+            stream_lines(options.input_file(INPUT_FILE))?,
+            &params.bag.colours()
+        )?
+    );
+    options
+        .print_explanation(|| explain(stream_lines(options.input_file(INPUT_FILE))?, &params.bag))
+}
+
+/// Why each impossible game in part 1 is impossible: every draw showing more cubes of a colour
+/// than the bag holds.
+fn explain(input: impl LineSource, bag: &Bag) -> Result<Explanation> {
+    let mut explanation = Explanation::default();
+    let section = explanation.section(
+        "cubes shown beyond the bag",
+        &["game", "draw", "colour", "shown", "in bag"],
+    );
+    fold_lines(input, (), |_, line| {
+        let game = parse_game(&line, &bag.colours())?;
+        for (i, draw) in game.draws.iter().enumerate() {
+            for (colour, &count) in &draw.0 {
+                if count > bag.count(colour) {
+                    section.row([
+                        game.id.into(),
+                        (i + 1).into(),
+                        colour.as_str().into(),
+                        count.into(),
+                        bag.count(colour).into(),
+                    ]);
+                }
+            }
+        }
+        Ok::<_, anyhow::Error>(())
+    })?;
+    Ok(explanation)
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+struct Params {
+    /// the cubes in the bag for part 1, whose colours are the only ones the games may show
+    bag: Bag,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            bag: Cubes::from([("red", 12), ("green", 13), ("blue", 14)]),
+        }
+    }
+}
+
+fn problem1_solution(input: impl LineSource, bag: &Bag) -> Result<usize> {
+    let colours = bag.colours();
+    sum_lines(input, |line| {
+        let game = parse_game(&line, &colours)?;
+        Ok(if game.is_possible_with(bag) {
+            game.id
+        } else {
+            0
+        })
+    })
+}
+
+fn problem2_solution(input: impl LineSource, colours: &[&str]) -> Result<usize> {
+    sum_lines(input, |line| Ok(parse_game(&line, colours)?.power()))
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Game {
+    id: usize,
+    draws: Vec<Draw>,
+}
+
+/// A number of cubes of each colour, either revealed in a draw or held in a bag.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+struct Cubes(BTreeMap<String, usize>);
+
+type Draw = Cubes;
+type Bag = Cubes;
+
+impl<const N: usize> From<[(&str, usize); N]> for Cubes {
+    fn from(counts: [(&str, usize); N]) -> Self {
+        Cubes(
+            counts
+                .into_iter()
+                .map(|(colour, count)| (colour.to_owned(), count))
+                .collect(),
+        )
+    }
+}
+
+impl Cubes {
+    fn colours(&self) -> Vec<&str> {
+        self.0.keys().map(String::as_str).collect()
+    }
+
+    fn count(&self, colour: &str) -> usize {
+        self.0.get(colour).copied().unwrap_or_default()
+    }
+
+    /// Whether every cube in `other` could have come from these cubes.
+    fn contains(&self, other: &Cubes) -> bool {
+        other
+            .0
+            .iter()
+            .all(|(colour, &count)| count <= self.count(colour))
+    }
+
+    /// The smallest collection of cubes containing both `self` and `other`.
+    fn union(mut self, other: &Cubes) -> Cubes {
+        for (colour, &count) in &other.0 {
+            let entry = self.0.entry(colour.clone()).or_default();
+            *entry = count.max(*entry);
+        }
+        self
+    }
+
+    fn power(&self) -> usize {
+        self.0.values().product()
+    }
+}
+
+impl Game {
+    fn is_possible_with(&self, bag: &Bag) -> bool {
+        self.draws.iter().all(|draw| bag.contains(draw))
+    }
+
+    fn minimum_bag(&self) -> Bag {
+        self.draws.iter().fold(Cubes::default(), Cubes::union)
+    }
+
+    fn power(&self) -> usize {
+        self.minimum_bag().power()
+    }
+}
+
+/// Parses a game in which every cube is one of `colours`. Each draw counts every colour, even
+/// if none of that colour were revealed.
+fn parse_game(line: &Line, colours: &[&str]) -> Result<Game, ParseError> {
+    let (id, draws_txt): (usize, &str) = scan!(line, "Game {id}: {draws}")?;
+    let expected_colour = colours.join(" or ");
+    let draws = draws_txt
+        .split(';')
+        .map(|draw_txt| {
+            let mut draw = Cubes(colours.iter().map(|&c| (c.to_owned(), 0)).collect());
+            for cubes_txt in draw_txt.split(',') {
+                let (count, colour): (usize, &str) = scan!(line, cubes_txt, "{count} {colour}")?;
+                *draw
+                    .0
+                    .get_mut(colour)
+                    .ok_or_else(|| line.error(colour, &expected_colour))? += count;
+            }
+            Ok(draw)
+        })
+        .collect::<Result<Vec<Draw>, ParseError>>()?;
+    Ok(Game { id, draws })
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use aoc_core::input::memory_lines;
+    use aoc_core::parse::lines;
+
+    const COLOURS: [&str; 3] = ["red", "green", "blue"];
+
+    const INPUT: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+    fn load_test_data() -> Vec<String> {
+        INPUT.lines().map(|s| s.to_owned()).collect()
+    }
+
+    #[test]
+    fn problem1() {
+        let bag = Params::default().bag;
+        let answer = problem1_solution(memory_lines(&load_test_data()), &bag).unwrap();
+        assert_eq!(answer, 8);
+        let bag = Cubes::from([("red", 20), ("green", 13), ("blue", 15)]);
+        let answer = problem1_solution(memory_lines(&load_test_data()), &bag).unwrap();
+        assert_eq!(answer, 15);
+    }
+
+    #[test]
+    fn colours_from_bag() {
+        let input = ["Game 1: 2 purple, 1 red; 3 purple", "Game 2: 5 purple"];
+        let bag = Cubes::from([("red", 1), ("purple", 3)]);
+        assert_eq!(bag.colours(), ["purple", "red"]);
+        let answer = problem1_solution(memory_lines(&input), &bag).unwrap();
+        assert_eq!(answer, 1);
+        let answer = problem2_solution(memory_lines(&input), &bag.colours()).unwrap();
+        // game 2 shows no red, so its power is nothing
+        assert_eq!(answer, 3);
+        let error = problem1_solution(memory_lines(&load_test_data()), &bag).unwrap_err();
+        assert!(
+            error.to_string().contains("expected purple or red"),
+            "{}",
+            error
+        );
+    }
+
+    #[test]
+    fn malformed_game() {
+        let line = Line::new(4, "Game 5: 6 red, 1 purple; 2 blue");
+        let error = parse_game(&line, &COLOURS).unwrap_err();
+        assert_eq!((error.line, error.column), (5, 18));
+        assert_eq!(error.text, "purple");
+        assert_eq!(error.expected, "red or green or blue");
+
+        let game = parse_game(&line, &["red", "purple", "blue"]).unwrap();
+        assert_eq!(
+            game.minimum_bag(),
+            Cubes::from([("red", 6), ("purple", 1), ("blue", 2)])
+        );
+    }
+
+    #[test]
+    fn bag_queries() {
+        let input = load_test_data();
+        let games = lines(&input)
+            .map(|line| parse_game(&line, &COLOURS))
+            .collect::<Result<Vec<Game>, ParseError>>()
+            .unwrap();
+        let small_bag = Cubes::from([("red", 4), ("green", 3), ("blue", 6)]);
+        let possible = games
+            .iter()
+            .filter(|game| game.is_possible_with(&small_bag))
+            .map(|game| game.id)
+            .collect::<Vec<usize>>();
+        assert_eq!(possible, vec![1, 2]);
+        assert_eq!(
+            games[0].minimum_bag(),
+            Cubes::from([("red", 4), ("green", 2), ("blue", 6)])
+        );
+        assert_eq!(
+            games.iter().map(Game::power).collect::<Vec<usize>>(),
+            vec![48, 12, 1560, 630, 36]
+        );
+    }
+
+    #[test]
+    fn problem2() {
+        let answer = problem2_solution(memory_lines(&load_test_data()), &COLOURS).unwrap();
+        assert_eq!(answer, 2286);
+    }
+
+    #[test]
+    fn explains() {
+        let bag = Params::default().bag;
+        let explanation = explain(memory_lines(&load_test_data()), &bag).unwrap();
+        let violations = explanation.sections[0]
+            .rows
+            .iter()
+            .map(|row| (row[0].as_u64().unwrap(), row[2].as_str().unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(violations, [(3, "red"), (4, "blue"), (4, "red")]);
+        assert_eq!(explanation.sections[0].rows[0][3], 20);
+    }
+}
@@ -0,0 +1,137 @@
+use anyhow::Result;
+use aoc_core::input::open;
+use aoc_core::parse::{lines, ParseError};
+use aoc_core::runner::Options;
+use regex::Regex;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Error};
+use std::ops::{Range, RangeInclusive};
+
+const INPUT_FILE: &str = "./data/2023/day03.txt";
+
+pub fn run(options: &Options) -> Result<()> {
+    let input_file = open(options.input_file(INPUT_FILE))?;
+    let lines: Vec<String> = BufReader::new(input_file)
+        .lines()
+        .collect::<Result<Vec<String>, Error>>()?;
+
+    println!("problem1 = {}", problem1_solution(&lines)?);
+    println!("problem2 = {}", problem2_solution(&lines)?);
+    Ok(())
+}
+
+fn problem1_solution(input: &[String]) -> Result<usize, ParseError> {
+    check_rectangular(input)?;
+    let re = Regex::new(r"\d+").unwrap();
+    let mut sum = 0;
+    for line in lines(input) {
+        let y = line.index;
+        let ys = expand_range_inc(y..=y, 0..input.len());
+        for m in re.find_iter(line.text) {
+            let xs = expand_range(m.range(), 0..line.text.len());
+
+            if has_symbol(&input[ys.clone()], xs) {
+                let n: usize = line.parse(m.as_str(), "a part number")?;
+                sum += n;
+            }
+        }
+    }
+    Ok(sum)
+}
+
+fn has_symbol(input: &[String], xs: RangeInclusive<usize>) -> bool {
+    let re = Regex::new(r"[^.\d]").unwrap();
+    input.iter().any(|line| re.is_match(&line[xs.clone()]))
+}
+
+fn problem2_solution(input: &[String]) -> Result<usize, ParseError> {
+    check_rectangular(input)?;
+    let re = Regex::new(r"\d+").unwrap();
+    let mut gears: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+    for line in lines(input) {
+        let y = line.index;
+        let ys = expand_range_inc(y..=y, 0..input.len());
+        for m in re.find_iter(line.text) {
+            let xs = expand_range(m.range(), 0..line.text.len());
+            let n: usize = line.parse(m.as_str(), "a part number")?;
+
+            for gy in ys.clone() {
+                for gx in xs.clone() {
+                    if input[gy].as_bytes()[gx] == b'*' {
+                        let gear_nums = match gears.entry((gx, gy)) {
+                            Entry::Occupied(o) => o.into_mut(),
+                            Entry::Vacant(v) => v.insert(vec![]),
+                        };
+                        gear_nums.push(n);
+                    }
+                }
+            }
+        }
+    }
+    Ok(gears
+        .values()
+        .filter(|nums| nums.len() == 2)
+        .map(|nums| nums[0] * nums[1])
+        .sum())
+}
+
+fn check_rectangular(input: &[String]) -> Result<(), ParseError> {
+    let width = input.first().map(|line| line.len()).unwrap_or_default();
+    match lines(input).find(|line| line.text.len() != width || !line.text.is_ascii()) {
+        Some(line) => Err(line.error(
+            line.text,
+            &format!("{} ascii characters to match the first line", width),
+        )),
+        None => Ok(()),
+    }
+}
+
+fn expand_range_inc(input: RangeInclusive<usize>, bounds: Range<usize>) -> RangeInclusive<usize> {
+    expand_range(*input.start()..(*input.end() + 1), bounds)
+}
+
+fn expand_range(input: Range<usize>, bounds: Range<usize>) -> RangeInclusive<usize> {
+    input.start.saturating_sub(1).max(bounds.start)..=input.end.min(bounds.end - 1)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    const INPUT: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+    fn load_test_data() -> Vec<String> {
+        INPUT.lines().map(|s| s.to_owned()).collect()
+    }
+
+    #[test]
+    fn problem1() {
+        let answer = problem1_solution(&load_test_data()).unwrap();
+        assert_eq!(answer, 4361);
+    }
+
+    #[test]
+    fn ragged_grid() {
+        let mut input = load_test_data();
+        input[3].push('.');
+        let error = problem1_solution(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (4, 1));
+    }
+
+    #[test]
+    fn problem2() {
+        let answer = problem2_solution(&load_test_data()).unwrap();
+        assert_eq!(answer, 467835);
+    }
+}
@@ -0,0 +1,200 @@
+use anyhow::Result;
+use aoc_core::assumptions::Assumption;
+use aoc_core::explain::{Explanation, Value};
+use aoc_core::input::{fold_lines, stream_lines, sum_lines, LineSource};
+use aoc_core::parse::{lines, Line, ParseError};
+use aoc_core::runner::Options;
+use itertools::Itertools;
+use std::collections::VecDeque;
+
+const INPUT_FILE: &str = "./data/2023/day04.txt";
+
+pub fn run(options: &Options) -> Result<()> {
+    println!(
+        "problem1 = {}",
+        problem1_solution(stream_lines(options.input_file(INPUT_FILE))?)?
+    );
+    println!(
+        "problem2 = {}",
+        problem2_solution(stream_lines(options.input_file(INPUT_FILE))?)?
+    );
+    options.print_explanation(|| explain(stream_lines(options.input_file(INPUT_FILE))?))
+}
+
+/// How the copies cascade in part 2: how many of each card there end up being, and which cards
+/// each copy of it wins.
+fn explain(input: impl LineSource) -> Result<Explanation> {
+    let mut explanation = Explanation::default();
+    let section = explanation.section("cards", &["card", "matches", "copies", "wins copies of"]);
+    fold_copies(input, (), |_, card, win_count, card_count| {
+        let won = match win_count {
+            0 => Value::Null,
+            1 => format!("card {}", card + 1).into(),
+            n => format!("cards {} to {}", card + 1, card + n).into(),
+        };
+        section.row([card.into(), win_count.into(), card_count.into(), won]);
+    })?;
+    Ok(explanation)
+}
+
+pub fn check(options: &Options) -> Result<Vec<Assumption>> {
+    let input =
+        stream_lines(options.input_file(INPUT_FILE))?.collect::<Result<Vec<String>, _>>()?;
+    assumptions(&input)
+}
+
+/// Part 2 takes each card's copies to come from the cards before it in the list, and lets copies
+/// won past the end of the table drop, as the puzzle says none are.
+fn assumptions(input: &[String]) -> Result<Vec<Assumption>> {
+    let mut out_of_order = None;
+    let mut past_end = None;
+    let mut repeats = None;
+    for line in lines(input) {
+        let (label, numbers_txt) = line.split_once(line.text, ": ")?;
+        let number: usize = line.parse(label.trim_start_matches("Card").trim(), "a card number")?;
+        if number != line.index + 1 && out_of_order.is_none() {
+            out_of_order = Some(format!("card {} is line {}", number, line.index + 1));
+        }
+        let win_count = calc_win_count(&line)?;
+        if line.index + win_count >= input.len() && past_end.is_none() {
+            past_end = Some(format!(
+                "card {} wins copies of the {} cards after it, but only {} follow",
+                number,
+                win_count,
+                input.len() - line.index - 1
+            ));
+        }
+        let (lhs_txt, rhs_txt) = line.split_once(numbers_txt, " | ")?;
+        for side in [lhs_txt, rhs_txt] {
+            let numbers: Vec<usize> = line.numbers(side)?;
+            if let Some(n) = numbers.iter().duplicates().next() {
+                repeats = repeats.or(Some(format!("card {} has {} twice", number, n)));
+            }
+        }
+    }
+    Ok(vec![
+        Assumption::new("the cards are listed in order, from card 1", out_of_order),
+        Assumption::new("no card wins copies of cards past the end", past_end),
+        Assumption::new("no number appears twice on one side of a card", repeats),
+    ])
+}
+
+fn problem1_solution(input: impl LineSource) -> Result<usize> {
+    sum_lines(input, |line| {
+        let count = calc_win_count(&line)?;
+        Ok(count
+            .checked_sub(1)
+            .map(|count| 1 << count)
+            .unwrap_or_default())
+    })
+}
+
+fn problem2_solution(input: impl LineSource) -> Result<usize> {
+    fold_copies(input, 0, |total, _, _, card_count| total + card_count)
+}
+
+/// Folds `f` over the cards, given each card's number, how many winning numbers it has, and how
+/// many copies of it there end up being.
+fn fold_copies<B>(
+    input: impl LineSource,
+    init: B,
+    mut f: impl FnMut(B, usize, usize, usize) -> B,
+) -> Result<B> {
+    // copies won of the cards following the current one, so only as many cards as the most
+    // winning numbers on a card need to be remembered
+    let mut won_copies: VecDeque<usize> = VecDeque::new();
+    fold_lines(input, init, |acc, line| {
+        let win_count = calc_win_count(&line)?;
+        let card_count = 1 + won_copies.pop_front().unwrap_or_default();
+        if won_copies.len() < win_count {
+            won_copies.resize(win_count, 0);
+        }
+        for copies in won_copies.iter_mut().take(win_count) {
+            *copies += card_count;
+        }
+        Ok(f(acc, line.index + 1, win_count, card_count))
+    })
+}
+
+fn calc_win_count(line: &Line) -> Result<usize, ParseError> {
+    let (_, numbers_txt) = line.split_once(line.text, ": ")?;
+    let (lhs_txt, rhs_txt) = line.split_once(numbers_txt, " | ")?;
+    let lhs: Vec<usize> = line.numbers(lhs_txt)?;
+    let rhs: Vec<usize> = line.numbers(rhs_txt)?;
+    Ok(rhs.iter().filter(|n| lhs.contains(n)).count())
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use aoc_core::input::memory_lines;
+
+    const INPUT: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+    fn load_test_data() -> Vec<String> {
+        INPUT.lines().map(|s| s.to_owned()).collect()
+    }
+
+    #[test]
+    fn problem1() {
+        let answer = problem1_solution(memory_lines(&load_test_data())).unwrap();
+        assert_eq!(answer, 13);
+    }
+
+    #[test]
+    fn malformed_card() {
+        let line = Line::new(0, "Card 1: 41 48 x3 | 83 86");
+        let error = calc_win_count(&line).unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.text.as_str()),
+            (1, 15, "x3")
+        );
+    }
+
+    #[test]
+    fn problem2() {
+        let answer = problem2_solution(memory_lines(&load_test_data())).unwrap();
+        assert_eq!(answer, 30);
+    }
+
+    #[test]
+    fn explains() {
+        let explanation = explain(memory_lines(&load_test_data())).unwrap();
+        let rows = &explanation.sections[0].rows;
+        let copies = rows
+            .iter()
+            .map(|row| row[2].as_u64().unwrap())
+            .collect_vec();
+        assert_eq!(copies, [1, 2, 4, 8, 14, 1]);
+        assert_eq!(rows[0][3], "cards 2 to 5");
+        assert_eq!(rows[3][3], "card 5");
+        assert!(rows[4][3].is_null());
+    }
+
+    #[test]
+    fn checks_assumptions() {
+        let mut input = load_test_data();
+        assert!(assumptions(&input).unwrap().iter().all(Assumption::holds));
+        input.swap(0, 1);
+        input[5] = "Card 6: 31 18 13 56 72 | 31 31 10 23 35 67 36 11".to_owned();
+        let violations = assumptions(&input)
+            .unwrap()
+            .into_iter()
+            .map(|a| a.violation.unwrap())
+            .collect_vec();
+        assert_eq!(
+            violations,
+            [
+                "card 2 is line 1",
+                "card 6 wins copies of the 2 cards after it, but only 0 follow",
+                "card 6 has 31 twice"
+            ]
+        );
+    }
+}
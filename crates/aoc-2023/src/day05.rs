@@ -0,0 +1,355 @@
+use anyhow::{anyhow, Context, Result};
+use aoc_core::assumptions::Assumption;
+use aoc_core::explain::Explanation;
+use aoc_core::input::open;
+use aoc_core::parse::{end_of_input, sections, ParseError};
+use aoc_core::runner::{Options, Solver};
+use aoc_core::scan;
+use itertools::Itertools;
+use std::io::{BufRead, BufReader, Error};
+
+const INPUT_FILE: &str = "./data/2023/day05.txt";
+
+/// Solves the puzzle. With `--explain`, also follows a seed through the maps: the seed given as
+/// an argument, or else the first.
+pub fn run(options: &Options) -> Result<()> {
+    let input_file = open(options.input_file(INPUT_FILE))?;
+    let lines: Vec<String> = BufReader::new(input_file)
+        .lines()
+        .collect::<Result<Vec<String>, Error>>()?;
+
+    println!("problem1 = {}", problem1_solution(&lines, map_ranges)?);
+    println!("problem2 = {}", problem2_solution(&lines, map_ranges)?);
+    options.print_explanation(|| {
+        let seed = options
+            .positional
+            .first()
+            .map(|s| {
+                s.parse()
+                    .with_context(|| format!("Expected a seed number, got `{}`", s))
+            })
+            .transpose()?;
+        explain(&lines, seed)
+    })
+}
+
+/// Following each seed through the maps, and splitting ranges of seeds wherever a map does, for
+/// `aoc diff`.
+pub static SOLVERS: &[Solver] = &[
+    Solver::new(1, "per-seed", |input| {
+        Ok(problem1_solution(input, map_seeds)?.to_string())
+    }),
+    Solver::new(1, "ranges", |input| {
+        Ok(problem1_solution(input, map_ranges)?.to_string())
+    }),
+    Solver::new(2, "per-seed", |input| {
+        Ok(problem2_solution(input, map_seeds)?.to_string())
+    }),
+    Solver::new(2, "ranges", |input| {
+        Ok(problem2_solution(input, map_ranges)?.to_string())
+    }),
+];
+
+type Map = Vec<(usize, usize, usize)>;
+
+/// Finds the lowest location of any seed in the ranges, given as starts and lengths.
+type Solve = fn(Vec<(usize, usize)>, &[Map]) -> Result<usize>;
+
+pub fn check(options: &Options) -> Result<Vec<Assumption>> {
+    let input_file = open(options.input_file(INPUT_FILE))?;
+    let lines: Vec<String> = BufReader::new(input_file)
+        .lines()
+        .collect::<Result<Vec<String>, Error>>()?;
+    assumptions(&lines)
+}
+
+/// Each id is mapped by the first range containing it, and the maps are applied in the order
+/// they're listed, so neither the order of a map's ranges nor its name is ever looked at.
+fn assumptions(input: &[String]) -> Result<Vec<Assumption>> {
+    let (seeds, maps) = parse_input(input)?;
+    let names = map_names(input);
+    let mut out_of_chain = None;
+    let mut expected = "seed";
+    for (from, to) in names.iter().chain([&("location", "")]) {
+        if *from != expected {
+            out_of_chain = Some(format!("expected a map from {}, found {}", expected, from));
+            break;
+        }
+        expected = to;
+    }
+    let overlap = maps.iter().zip(&names).find_map(|(map, (from, to))| {
+        let (a, b) = map
+            .iter()
+            .sorted_by_key(|&&(_, source, _)| source)
+            .tuple_windows()
+            .find(|(a, b)| a.1 + a.2 > b.1)?;
+        Some(format!(
+            "in {}-to-{}, the ranges from {} and {} overlap",
+            from, to, a.1, b.1
+        ))
+    });
+    Ok(vec![
+        Assumption::check("the seeds come in pairs", seeds.len() % 2 == 0, || {
+            format!("there are {} seeds", seeds.len())
+        }),
+        Assumption::new(
+            "the maps lead from seed to location, in order",
+            out_of_chain,
+        ),
+        Assumption::new(
+            "once sorted, each map's source ranges don't overlap",
+            overlap,
+        ),
+    ])
+}
+
+/// The path of a seed through the maps, by default the first seed.
+fn explain(input: &[String], seed: Option<usize>) -> Result<Explanation> {
+    let (seeds, maps) = parse_input(input)?;
+    let seed = seed.or(seeds.first().copied()).ok_or(anyhow!("No seeds"))?;
+    let mut explanation = Explanation::default();
+    let section = explanation.section(format!("seed {}", seed), &["map", "from", "to"]);
+    let mut id = seed;
+    for (map, (from, to)) in maps.iter().zip(map_names(input)) {
+        let next = next_id(id, map);
+        section.row([format!("{}-to-{}", from, to).into(), id.into(), next.into()]);
+        id = next;
+    }
+    Ok(explanation)
+}
+
+fn problem1_solution(input: &[String], solve: Solve) -> Result<usize> {
+    let (seeds, maps) = parse_input(input)?;
+    solve(seeds.into_iter().map(|seed| (seed, 1)).collect(), &maps)
+}
+
+fn problem2_solution(input: &[String], solve: Solve) -> Result<usize> {
+    let (seed_ranges, maps) = parse_input(input)?;
+    solve(seed_ranges.into_iter().tuples().collect(), &maps)
+}
+
+/// Follows every seed through the maps one at a time, which is simple but takes as long as
+/// there are seeds.
+fn map_seeds(ranges: Vec<(usize, usize)>, maps: &[Map]) -> Result<usize> {
+    ranges
+        .into_iter()
+        .flat_map(|(start, len)| start..start + len)
+        .map(|seed| maps.iter().fold(seed, next_id))
+        .min()
+        .ok_or(anyhow!("No seeds"))
+}
+
+/// Follows whole ranges of ids through the maps, splitting them where a map's ranges start and
+/// end. Each map's ranges are tried in the order they're listed, as with [`next_id`], so a piece
+/// which one range has mapped isn't mapped again by a later one it overlaps.
+fn map_ranges(mut ranges: Vec<(usize, usize)>, maps: &[Map]) -> Result<usize> {
+    for map in maps {
+        let mut mapped = vec![];
+        for &(to_start, from_start, len) in map {
+            let from_end = from_start + len;
+            let mut unmapped = vec![];
+            for (start, range_len) in ranges {
+                let end = start + range_len;
+                let (inside_start, inside_end) = (start.max(from_start), end.min(from_end));
+                if inside_start >= inside_end {
+                    unmapped.push((start, range_len));
+                    continue;
+                }
+                mapped.push((
+                    to_start + inside_start - from_start,
+                    inside_end - inside_start,
+                ));
+                if start < inside_start {
+                    unmapped.push((start, inside_start - start));
+                }
+                if inside_end < end {
+                    unmapped.push((inside_end, end - inside_end));
+                }
+            }
+            ranges = unmapped;
+        }
+        ranges.extend(mapped);
+    }
+    ranges
+        .into_iter()
+        .filter(|&(_, len)| len > 0)
+        .map(|(start, _)| start)
+        .min()
+        .ok_or(anyhow!("No seeds"))
+}
+
+fn next_id(id: usize, map: &Map) -> usize {
+    map.iter()
+        .filter_map(|&(to_start, from_start, len)| {
+            let offset = id.checked_sub(from_start)?;
+            (offset < len).then_some(to_start + offset)
+        })
+        .next()
+        .unwrap_or(id)
+}
+
+/// The kinds of id each map is from and to, from their headers, e.g. `("seed", "soil")`.
+fn map_names(input: &[String]) -> Vec<(&str, &str)> {
+    sections(input)[1..]
+        .iter()
+        .map(|section| {
+            let header = section[0].text.strip_suffix(" map:").unwrap_or_default();
+            header.split_once("-to-").unwrap_or_default()
+        })
+        .collect()
+}
+
+fn parse_input(input: &[String]) -> Result<(Vec<usize>, Vec<Map>), ParseError> {
+    let sections = sections(input);
+    let seeds_line = sections
+        .first()
+        .and_then(|section| section.first())
+        .ok_or_else(|| end_of_input(input, "a list of seeds"))?;
+    let (seeds,): (Vec<usize>,) = scan!(seeds_line, "seeds: {seeds}")?;
+    let maps = sections[1..]
+        .iter()
+        .map(|section| {
+            section
+                .iter()
+                .skip(1)
+                .map(|line| scan!(line, "{destination} {source} {length}"))
+                .collect()
+        })
+        .collect::<Result<Vec<Map>, ParseError>>()?;
+    Ok((seeds, maps))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators;
+    use aoc_core::differential;
+    use aoc_core::random::Rng;
+
+    const INPUT: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+    fn load_test_data() -> Vec<String> {
+        INPUT.lines().map(|s| s.to_owned()).collect()
+    }
+
+    #[test]
+    fn problem1() {
+        let answer = problem1_solution(&load_test_data(), map_seeds).unwrap();
+        assert_eq!(answer, 35);
+        let answer = problem1_solution(&load_test_data(), map_ranges).unwrap();
+        assert_eq!(answer, 35);
+    }
+
+    #[test]
+    fn short_map_line() {
+        let mut input = load_test_data();
+        input[4] = "52 50".to_owned();
+        let error = parse_input(&input).unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.text.as_str()),
+            (5, 4, "50")
+        );
+    }
+
+    #[test]
+    fn problem2() {
+        let answer = problem2_solution(&load_test_data(), map_seeds).unwrap();
+        assert_eq!(answer, 46);
+        let answer = problem2_solution(&load_test_data(), map_ranges).unwrap();
+        assert_eq!(answer, 46);
+    }
+
+    #[test]
+    fn explains() {
+        let explanation = explain(&load_test_data(), Some(14)).unwrap();
+        let section = &explanation.sections[0];
+        assert_eq!(section.title, "seed 14");
+        assert_eq!(section.rows.len(), 7);
+        assert_eq!(section.rows[2][0], "fertilizer-to-water");
+        assert_eq!(section.rows[2][1], 53);
+        assert_eq!(section.rows[2][2], 49);
+        assert_eq!(section.rows[6][2], 43);
+        let explanation = explain(&load_test_data(), None).unwrap();
+        assert_eq!(explanation.sections[0].title, "seed 79");
+    }
+
+    #[test]
+    fn solves_generated_almanacs() {
+        for seed in 0..5 {
+            let input = generators::day05(&mut Rng::new(seed), 8);
+            assert!(assumptions(&input).unwrap().iter().all(Assumption::holds));
+            problem1_solution(&input, map_ranges).unwrap();
+            problem2_solution(&input, map_ranges).unwrap();
+        }
+    }
+
+    #[test]
+    fn implementations_agree() {
+        let found = differential::search(SOLVERS, generators::day05, 10, 0, 50);
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    fn ranges_overlapping_in_a_map() {
+        // the first of the map's ranges wins where they overlap, as it does for a single seed
+        let maps = vec![vec![(100, 5, 10), (200, 0, 20)]];
+        let ranges = vec![(0, 5), (10, 2)];
+        assert_eq!(map_ranges(ranges.clone(), &maps).unwrap(), 105);
+        assert_eq!(map_seeds(ranges, &maps).unwrap(), 105);
+        assert!(map_ranges(vec![(3, 0)], &maps).is_err());
+    }
+
+    #[test]
+    fn checks_assumptions() {
+        let mut input = load_test_data();
+        assert!(assumptions(&input).unwrap().iter().all(Assumption::holds));
+        input[0] = "seeds: 79 14 55".to_owned();
+        input[6] = "soil-to-water map:".to_owned();
+        input[9] = "39 0 16".to_owned();
+        let violations = assumptions(&input)
+            .unwrap()
+            .into_iter()
+            .map(|a| a.violation.unwrap())
+            .collect_vec();
+        assert_eq!(
+            violations,
+            [
+                "there are 3 seeds",
+                "expected a map from water, found fertilizer",
+                "in soil-to-water, the ranges from 0 and 15 overlap"
+            ]
+        );
+    }
+}
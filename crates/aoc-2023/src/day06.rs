@@ -0,0 +1,169 @@
+use anyhow::Result;
+use aoc_core::input::open;
+use aoc_core::parse::{Line, ParseError};
+use aoc_core::runner::{Options, Solver};
+use std::io::{BufRead, BufReader, Error};
+
+const INPUT_FILE: &str = "./data/2023/day06.txt";
+
+pub fn run(options: &Options) -> Result<()> {
+    let input_file = open(options.input_file(INPUT_FILE))?;
+    let lines: Vec<String> = BufReader::new(input_file)
+        .lines()
+        .collect::<Result<Vec<String>, Error>>()?;
+
+    println!("problem1 = {}", problem1_solution(&lines, solve_roots)?);
+    println!("problem2 = {}", problem2_solution(&lines, solve_roots)?);
+    Ok(())
+}
+
+/// Counting every winning charge time, and solving for the first and last, for `aoc diff`.
+pub static SOLVERS: &[Solver] = &[
+    Solver::new(1, "brute-force", |input| {
+        Ok(problem1_solution(input, count_winners)?.to_string())
+    }),
+    Solver::new(1, "closed-form", |input| {
+        Ok(problem1_solution(input, solve_roots)?.to_string())
+    }),
+    Solver::new(2, "brute-force", |input| {
+        Ok(problem2_solution(input, count_winners)?.to_string())
+    }),
+    Solver::new(2, "closed-form", |input| {
+        Ok(problem2_solution(input, solve_roots)?.to_string())
+    }),
+];
+
+/// Counts the ways of beating the record of a race, given its time and the record.
+type Winners = fn(usize, usize) -> usize;
+
+fn problem1_solution(input: &[String], winners: Winners) -> Result<usize, ParseError> {
+    let times: Vec<usize> = parse_row(input, 0, "Time:")?;
+    let dists: Vec<usize> = parse_row(input, 1, "Distance:")?;
+    Ok(times
+        .into_iter()
+        .zip(dists)
+        .map(|(t, d)| winners(t, d))
+        .product())
+}
+
+fn problem2_solution(input: &[String], winners: Winners) -> Result<usize, ParseError> {
+    let time = parse_kerned(input, 0, "Time:")?;
+    let dist = parse_kerned(input, 1, "Distance:")?;
+    Ok(winners(time, dist))
+}
+
+fn parse_row(input: &[String], index: usize, label: &str) -> Result<Vec<usize>, ParseError> {
+    let line = Line::get(input, index, label)?;
+    line.numbers(line.strip_prefix(line.text, label)?)
+}
+
+fn parse_kerned(input: &[String], index: usize, label: &str) -> Result<usize, ParseError> {
+    let line = Line::get(input, index, label)?;
+    let digits_txt = line.strip_prefix(line.text, label)?;
+    let digits = digits_txt
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>();
+    digits
+        .parse()
+        .map_err(|_| line.error(digits_txt, "a number"))
+}
+
+fn count_winners(t: usize, d: usize) -> usize {
+    (1..t)
+        .filter(|charge_time| {
+            let optimal_distance = (t - charge_time) * charge_time;
+            optimal_distance > d
+        })
+        .count()
+}
+
+/// The winning charge times are those between the roots of `c * (t - c) = d`, symmetric about
+/// `t / 2`. The square root is only a float, so the first winner is found from it and then
+/// nudged until it's exact.
+fn solve_roots(t: usize, d: usize) -> usize {
+    let wins = |c: usize| c as u128 * (t - c) as u128 > d as u128;
+    let discriminant = (t as f64).powi(2) - 4.0 * d as f64;
+    let mut first = if discriminant > 0.0 {
+        ((t as f64 - discriminant.sqrt()) / 2.0).max(0.0) as usize
+    } else {
+        t / 2
+    }
+    .min(t / 2);
+    while first > 0 && wins(first - 1) {
+        first -= 1;
+    }
+    while first <= t / 2 && !wins(first) {
+        first += 1;
+    }
+    if first > t / 2 {
+        0
+    } else {
+        t + 1 - 2 * first
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::generators;
+    use aoc_core::differential;
+
+    const INPUT: &str = "Time:      7  15   30
+Distance:  9  40  200";
+
+    fn load_test_data() -> Vec<String> {
+        INPUT.lines().map(|s| s.to_owned()).collect()
+    }
+
+    #[test]
+    fn problem1() {
+        let answer = problem1_solution(&load_test_data(), count_winners).unwrap();
+        assert_eq!(answer, 288);
+        let answer = problem1_solution(&load_test_data(), solve_roots).unwrap();
+        assert_eq!(answer, 288);
+    }
+
+    #[test]
+    fn problem2() {
+        let answer = problem2_solution(&load_test_data(), count_winners).unwrap();
+        assert_eq!(answer, 71503);
+        let answer = problem2_solution(&load_test_data(), solve_roots).unwrap();
+        assert_eq!(answer, 71503);
+    }
+
+    #[test]
+    fn missing_distances() {
+        let input = vec!["Time:      7  15   30".to_owned()];
+        let error = problem1_solution(&input, solve_roots).unwrap_err();
+        assert_eq!((error.line, error.expected.as_str()), (2, "Distance:"));
+    }
+
+    #[test]
+    fn roots_are_exact() {
+        for t in 0..60 {
+            for d in 0..t * t / 4 + 2 {
+                assert_eq!(
+                    solve_roots(t, d),
+                    count_winners(t, d),
+                    "t = {}, d = {}",
+                    t,
+                    d
+                );
+            }
+        }
+        // times around 2^30, so that their squares, around 2^60, are past the 2^53 up to which an
+        // f64 holds every integer exactly
+        let t = 1 << 30;
+        assert_eq!(solve_roots(t, t / 2 * (t / 2) - 1), 1);
+        assert_eq!(solve_roots(t, t / 2 * (t / 2)), 0);
+        assert_eq!(solve_roots(t, (t / 2 - 1) * (t / 2 + 1) - 1), 3);
+    }
+
+    #[test]
+    fn implementations_agree() {
+        let found = differential::search(SOLVERS, generators::day06, 3, 0, 30);
+        assert_eq!(found, None);
+    }
+}
@@ -0,0 +1,394 @@
+use anyhow::Result;
+use aoc_core::explain::Explanation;
+use aoc_core::input::{fold_lines, memory_lines, stream_lines, LineSource};
+use aoc_core::parse::{Line, ParseError};
+use aoc_core::runner::Options;
+use aoc_core::scan;
+use itertools::Itertools;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::PathBuf;
+
+const INPUT_FILE: &str = "./data/2023/day07.txt";
+
+/// Solves the puzzle, or with a file of hands as an argument, also prints how they rank.
+pub fn run(options: &Options) -> Result<()> {
+    let hands_file = options.positional.first().map(PathBuf::from);
+    let input_path = hands_file.clone().unwrap_or(options.input_file(INPUT_FILE));
+
+    if hands_file.is_some() {
+        for rules in [RuleSet::camel_cards(), RuleSet::camel_cards_with_jokers()] {
+            for ranked in rank_hands(&rules, stream_lines(&input_path)?)? {
+                println!(
+                    "{:>5} {} {:>5} {}",
+                    ranked.rank,
+                    ranked.hand,
+                    ranked.bid,
+                    rules.explain(ranked.classification)
+                );
+            }
+        }
+    }
+
+    println!(
+        "problem1 = {}",
+        problem1_solution(stream_lines(&input_path)?)?
+    );
+    println!(
+        "problem2 = {}",
+        problem2_solution(stream_lines(&input_path)?)?
+    );
+    options.print_explanation(|| explain(stream_lines(&input_path)?))
+}
+
+/// The hands in order of rank under each part's rules, with what each wins.
+fn explain(input: impl LineSource) -> Result<Explanation> {
+    let lines = input
+        .into_iter()
+        .map(|line| Ok(line?.as_ref().to_owned()))
+        .collect::<io::Result<Vec<String>>>()?;
+    let mut explanation = Explanation::default();
+    for (part, rules) in [RuleSet::camel_cards(), RuleSet::camel_cards_with_jokers()]
+        .iter()
+        .enumerate()
+    {
+        let section = explanation.section(
+            format!("part {}", part + 1),
+            &["rank", "hand", "category", "bid", "winnings"],
+        );
+        for ranked in rank_hands(rules, memory_lines(&lines))? {
+            section.row([
+                ranked.rank.into(),
+                ranked.hand.to_string().into(),
+                rules.explain(ranked.classification).into(),
+                ranked.bid.into(),
+                (ranked.rank * ranked.bid).into(),
+            ]);
+        }
+    }
+    Ok(explanation)
+}
+
+fn problem1_solution(input: impl LineSource) -> Result<usize> {
+    total_score(&RuleSet::camel_cards(), input)
+}
+
+fn problem2_solution(input: impl LineSource) -> Result<usize> {
+    total_score(&RuleSet::camel_cards_with_jokers(), input)
+}
+
+fn total_score(rules: &RuleSet, input: impl LineSource) -> Result<usize> {
+    Ok(rank_hands(rules, input)?
+        .iter()
+        .map(|ranked| ranked.rank * ranked.bid)
+        .sum())
+}
+
+/// A kind of hand, such as a full house, described by the sizes of its groups of matching cards.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Category {
+    name: &'static str,
+    /// group sizes, largest first
+    groups: Vec<usize>,
+}
+
+/// Rules for classifying and comparing hands.
+#[derive(Clone, Debug)]
+struct RuleSet {
+    /// cards from weakest to strongest, used to break ties between hands of the same category
+    card_order: Vec<char>,
+    /// cards which can stand in for any other card when classifying a hand
+    wild: Vec<char>,
+    hand_size: usize,
+    /// categories from weakest to strongest
+    categories: Vec<Category>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Hand {
+    cards: Vec<char>,
+}
+
+/// The strongest category a hand can make, and how many wild cards it needs to make it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct Classification {
+    category: usize,
+    wilds_used: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct RankedHand {
+    hand: Hand,
+    bid: usize,
+    classification: Classification,
+    /// 1-based rank, where 1 is the weakest hand
+    rank: usize,
+}
+
+impl RuleSet {
+    fn camel_cards() -> Self {
+        RuleSet {
+            card_order: "23456789TJQKA".chars().collect(),
+            wild: vec![],
+            hand_size: 5,
+            categories: vec![
+                Category::new("high card", &[1]),
+                Category::new("one pair", &[2]),
+                Category::new("two pair", &[2, 2]),
+                Category::new("three of a kind", &[3]),
+                Category::new("full house", &[3, 2]),
+                Category::new("four of a kind", &[4]),
+                Category::new("five of a kind", &[5]),
+            ],
+        }
+    }
+
+    fn camel_cards_with_jokers() -> Self {
+        RuleSet {
+            card_order: "J23456789TQKA".chars().collect(),
+            wild: vec!['J'],
+            ..RuleSet::camel_cards()
+        }
+    }
+
+    fn parse_hand(&self, line: &Line, text: &str) -> Result<Hand, ParseError> {
+        if text.chars().count() != self.hand_size {
+            return Err(line.error(text, &format!("a hand of {} cards", self.hand_size)));
+        }
+        for (i, c) in text.char_indices() {
+            if !self.card_order.contains(&c) {
+                return Err(line.error(&text[i..i + c.len_utf8()], "a card"));
+            }
+        }
+        Ok(Hand {
+            cards: text.chars().collect(),
+        })
+    }
+
+    /// Finds the strongest category the hand can make. Wild cards are used to make up any
+    /// shortfall between the hand's groups of natural cards and the category's groups, which is
+    /// smallest when the largest groups are paired up with each other.
+    fn classify(&self, hand: &Hand) -> Classification {
+        let wild_count = hand.cards.iter().filter(|c| self.wild.contains(c)).count();
+        let mut counts = hand
+            .cards
+            .iter()
+            .filter(|c| !self.wild.contains(c))
+            .counts()
+            .into_values()
+            .collect_vec();
+        counts.sort_by(|a, b| b.cmp(a));
+        self.categories
+            .iter()
+            .enumerate()
+            .rev()
+            .find_map(|(category, Category { groups, .. })| {
+                let wilds_used = groups
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &g)| g.saturating_sub(counts.get(i).copied().unwrap_or_default()))
+                    .sum();
+                (wilds_used <= wild_count).then_some(Classification {
+                    category,
+                    wilds_used,
+                })
+            })
+            .unwrap_or(Classification {
+                category: 0,
+                wilds_used: 0,
+            })
+    }
+
+    /// Orders hands by their category, as classified, then card by card.
+    fn sort_key(&self, hand: &Hand, classification: Classification) -> (usize, Vec<usize>) {
+        let strengths = hand
+            .cards
+            .iter()
+            .filter_map(|c| self.card_order.iter().position(|o| o == c))
+            .collect_vec();
+        (classification.category, strengths)
+    }
+
+    /// Describes a classification, naming the wild cards if it needs any, e.g. "full house via 1
+    /// wild card (J)".
+    fn explain(&self, classification: Classification) -> String {
+        let name = self.categories[classification.category].name;
+        let wild = match self.wild.as_slice() {
+            [] => String::new(),
+            [card] => card.to_string(),
+            [rest @ .., last] => format!("{} or {}", rest.iter().join(", "), last),
+        };
+        match classification.wilds_used {
+            0 => name.to_owned(),
+            1 => format!("{} via 1 wild card ({})", name, wild),
+            n => format!("{} via {} wild cards ({})", name, n, wild),
+        }
+    }
+}
+
+impl Display for Hand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.cards.iter().collect::<String>())
+    }
+}
+
+impl Category {
+    fn new(name: &'static str, groups: &[usize]) -> Self {
+        Category {
+            name,
+            groups: groups.to_vec(),
+        }
+    }
+}
+
+/// Parses lines of hands and bids, then ranks them from weakest to strongest. Ranking needs every
+/// hand at once, so unlike the other streaming days this holds all the parsed hands in memory,
+/// though not the lines they were parsed from.
+fn rank_hands(rules: &RuleSet, input: impl LineSource) -> Result<Vec<RankedHand>> {
+    let mut hands = fold_lines(input, vec![], |mut hands, line| {
+        let (hand_txt, bid): (&str, usize) = scan!(line, "{hand} {bid}")?;
+        let hand = rules.parse_hand(&line, hand_txt)?;
+        let classification = rules.classify(&hand);
+        hands.push((
+            rules.sort_key(&hand, classification),
+            classification,
+            hand,
+            bid,
+        ));
+        Ok::<_, anyhow::Error>(hands)
+    })?;
+    hands.sort_by(|(l_key, _, _, l_bid), (r_key, _, _, r_bid)| (l_key, l_bid).cmp(&(r_key, r_bid)));
+    Ok(hands
+        .into_iter()
+        .enumerate()
+        .map(|(i, (_, classification, hand, bid))| RankedHand {
+            classification,
+            hand,
+            bid,
+            rank: i + 1,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use aoc_core::input::memory_lines;
+
+    const INPUT: &str = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+    fn load_test_data() -> Vec<String> {
+        INPUT.lines().map(|s| s.to_owned()).collect()
+    }
+
+    fn hand(cards: &str) -> Hand {
+        Hand {
+            cards: cards.chars().collect(),
+        }
+    }
+
+    fn sort_key(rules: &RuleSet, cards: &str) -> (usize, Vec<usize>) {
+        let hand = hand(cards);
+        rules.sort_key(&hand, rules.classify(&hand))
+    }
+
+    #[test]
+    fn parse_jacks() {
+        let rules = RuleSet::camel_cards();
+        assert_eq!(sort_key(&rules, "33TTT"), (4, vec![1, 1, 8, 8, 8]));
+        assert_eq!(sort_key(&rules, "KTJJT"), (2, vec![11, 8, 9, 9, 8]));
+        assert_eq!(rules.explain(rules.classify(&hand("KTJJT"))), "two pair");
+    }
+
+    #[test]
+    fn parse_jokers() {
+        let rules = RuleSet::camel_cards_with_jokers();
+        assert_eq!(sort_key(&rules, "33TTT"), (4, vec![2, 2, 9, 9, 9]));
+        assert_eq!(sort_key(&rules, "KTJJT"), (5, vec![11, 9, 0, 0, 9]));
+        assert_eq!(
+            rules.explain(rules.classify(&hand("KTJJT"))),
+            "four of a kind via 2 wild cards (J)"
+        );
+        assert_eq!(
+            rules.explain(rules.classify(&hand("2JJJJ"))),
+            "five of a kind via 4 wild cards (J)"
+        );
+        assert_eq!(
+            rules.explain(rules.classify(&hand("KKJQQ"))),
+            "full house via 1 wild card (J)"
+        );
+    }
+
+    #[test]
+    fn variant_rules() {
+        // a six card variant with everything wild below 5, and a category for two triples
+        let mut rules = RuleSet::camel_cards();
+        rules.hand_size = 6;
+        rules.wild = vec!['2', '3', '4'];
+        rules
+            .categories
+            .insert(5, Category::new("two triples", &[3, 3]));
+        let line = Line::new(0, "A2KK3A");
+        let hand = rules.parse_hand(&line, line.text).unwrap();
+        assert_eq!(
+            rules.explain(rules.classify(&hand)),
+            "four of a kind via 2 wild cards (2, 3 or 4)"
+        );
+        rules.categories.truncate(6);
+        assert_eq!(
+            rules.explain(rules.classify(&hand)),
+            "two triples via 2 wild cards (2, 3 or 4)"
+        );
+        assert_eq!(
+            rules.parse_hand(&line, "A2KK3").unwrap_err().expected,
+            "a hand of 6 cards"
+        );
+    }
+
+    #[test]
+    fn bad_card() {
+        let rules = RuleSet::camel_cards();
+        let line = Line::new(0, "KT1JT 999");
+        let error = rules.parse_hand(&line, &line.text[..5]).unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (3, "1"));
+    }
+
+    #[test]
+    fn tolerates_whitespace() {
+        let input = ["  KTJJT \t 999 "];
+        let ranked = rank_hands(&RuleSet::camel_cards(), memory_lines(&input)).unwrap();
+        assert_eq!(
+            (ranked[0].hand.clone(), ranked[0].bid),
+            (hand("KTJJT"), 999)
+        );
+    }
+
+    #[test]
+    fn problem1() {
+        let answer = problem1_solution(memory_lines(&load_test_data())).unwrap();
+        assert_eq!(answer, 6440);
+    }
+
+    #[test]
+    fn problem2() {
+        let answer = problem2_solution(memory_lines(&load_test_data())).unwrap();
+        assert_eq!(answer, 5905);
+    }
+
+    #[test]
+    fn explains() {
+        let explanation = explain(memory_lines(&load_test_data())).unwrap();
+        let part2 = &explanation.sections[1];
+        assert_eq!(part2.title, "part 2");
+        assert_eq!(part2.rows[4][1], "KTJJT");
+        assert_eq!(part2.rows[4][2], "four of a kind via 2 wild cards (J)");
+        assert_eq!(part2.rows[4][4], 1100);
+        let winnings = part2.rows.iter().map(|row| row[4].as_u64().unwrap());
+        assert_eq!(winnings.sum::<u64>(), 5905);
+    }
+}
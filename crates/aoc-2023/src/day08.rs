@@ -0,0 +1,264 @@
+use anyhow::{anyhow, Result};
+use aoc_core::assumptions::Assumption;
+use aoc_core::input::open;
+use aoc_core::number::lcm_all;
+use aoc_core::parse::{end_of_input, sections, ParseError};
+use aoc_core::runner::Options;
+use aoc_core::scan;
+use itertools::Itertools;
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Error};
+
+const INPUT_FILE: &str = "./data/2023/day08.txt";
+
+pub fn run(options: &Options) -> Result<()> {
+    let input_file = open(options.input_file(INPUT_FILE))?;
+    let lines: Vec<String> = BufReader::new(input_file)
+        .lines()
+        .collect::<Result<Vec<String>, Error>>()?;
+
+    println!("problem1 = {}", problem1_solution(&lines)?);
+    println!("problem2 = {}", problem2_solution(&lines)?);
+    Ok(())
+}
+
+type Network<'a> = HashMap<&'a str, [&'a str; 2]>;
+
+fn problem1_solution(input: &[String]) -> Result<usize> {
+    let (moves, network) = parse(input)?;
+    let mut pos = "AAA";
+    let mut count = 0;
+    while pos != "ZZZ" {
+        pos = network.get(pos).ok_or(anyhow!("No node {}", pos))?[moves[count % moves.len()]];
+        count += 1;
+    }
+    Ok(count)
+}
+
+fn problem2_solution(input: &[String]) -> Result<usize> {
+    let (moves, network) = parse(input)?;
+    let mut cycle_lengths = vec![];
+    for &p in network.keys().filter(|&n| n.ends_with('A')) {
+        let (_, cycle) = search_from(&moves, &network, p);
+        cycle_lengths.push(cycle.len());
+    }
+    Ok(lcm_all(cycle_lengths))
+}
+
+pub fn check(options: &Options) -> Result<Vec<Assumption>> {
+    let input_file = open(options.input_file(INPUT_FILE))?;
+    let lines: Vec<String> = BufReader::new(input_file)
+        .lines()
+        .collect::<Result<Vec<String>, Error>>()?;
+    assumptions(&lines)
+}
+
+/// Part 2 takes the LCM of the ghosts' cycle lengths, which is only the answer if each ghost is
+/// on a Z node at every multiple of its cycle length and at no other step.
+fn assumptions(input: &[String]) -> Result<Vec<Assumption>> {
+    let (moves, network) = parse(input)?;
+    let mut early_z = None;
+    let mut z_count = None;
+    let mut off_multiple = None;
+    for &start in network.keys().filter(|&n| n.ends_with('A')).sorted() {
+        let (init, cycle) = search_from(&moves, &network, start);
+        if let Some(step) = init.iter().position(|&p| p.ends_with('Z')) {
+            early_z = early_z.or(Some(format!(
+                "the ghost from {} passes {} at step {}, before its cycle starts",
+                start, init[step], step
+            )));
+        }
+        let zs = cycle.iter().positions(|&p| p.ends_with('Z')).collect_vec();
+        if zs.len() != 1 {
+            z_count = z_count.or(Some(format!(
+                "the ghost from {} passes {} Z nodes in each cycle",
+                start,
+                zs.len()
+            )));
+        } else if init.len() + zs[0] != cycle.len() {
+            off_multiple = off_multiple.or(Some(format!(
+                "the ghost from {} first reaches {} after {} steps, but its cycle is {} long",
+                start,
+                cycle[zs[0]],
+                init.len() + zs[0],
+                cycle.len()
+            )));
+        }
+    }
+    Ok(vec![
+        Assumption::new("no ghost reaches a Z node before its cycle", early_z),
+        Assumption::new("each ghost passes one Z node per cycle", z_count),
+        Assumption::new(
+            "each ghost reaches Z exactly at multiples of its cycle length",
+            off_multiple,
+        ),
+    ])
+}
+
+struct Node<'a> {
+    name: &'a str,
+    left: &'a str,
+    right: &'a str,
+}
+
+fn parse(input: &[String]) -> Result<(Vec<usize>, Network<'_>), ParseError> {
+    let mut sections = sections(input).into_iter();
+    let moves_line = match sections.next().as_deref() {
+        Some(&[line]) => line,
+        Some(&[_, line, ..]) => return Err(line.error(line.text, "a blank line after the moves")),
+        _ => return Err(end_of_input(input, "a list of moves")),
+    };
+    let moves = moves_line
+        .text
+        .char_indices()
+        .map(|(i, c)| match c {
+            'L' => Ok(0usize),
+            'R' => Ok(1usize),
+            _ => Err(moves_line.error(&moves_line.text[i..i + c.len_utf8()], "L or R")),
+        })
+        .collect::<Result<Vec<usize>, ParseError>>()?;
+
+    let node_lines = sections
+        .next()
+        .ok_or_else(|| end_of_input(input, "a network of nodes"))?;
+    if let Some(line) = sections.next().and_then(|section| section.first().copied()) {
+        return Err(line.error(line.text, "end of input"));
+    }
+    let nodes = node_lines
+        .iter()
+        .map(|line| scan!(line, "{name} = ({left}, {right})" => Node { name, left, right }))
+        .collect::<Result<Vec<Node>, ParseError>>()?;
+    let network: Network = nodes
+        .iter()
+        .map(|node| (node.name, [node.left, node.right]))
+        .collect();
+    for (line, node) in node_lines.iter().zip(&nodes) {
+        for target in [node.left, node.right] {
+            if !network.contains_key(target) {
+                return Err(line.error(target, "a node defined in the network"));
+            }
+        }
+    }
+    Ok((moves, network))
+}
+
+fn search_from<'a>(
+    moves: &[usize],
+    network: &'a Network,
+    start: &'a str,
+) -> (Vec<&'a str>, Vec<&'a str>) {
+    let mut visited = vec![];
+    let mut current = start;
+    loop {
+        let offset = visited.len() % moves.len();
+        let current_pair = (current, offset);
+        if visited.contains(&current_pair) {
+            let mut i = visited.iter();
+            let initial = i
+                .by_ref()
+                .peeking_take_while(|&v| v != &current_pair)
+                .map(|&v| v.0)
+                .collect_vec();
+            let cycle = i.map(|&v| v.0).collect_vec();
+            return (initial, cycle);
+        }
+        visited.push(current_pair);
+        current = network[current][moves[offset]];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::generators;
+    use aoc_core::random::Rng;
+
+    const INPUT_1: &str = "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+
+    #[test]
+    fn problem1() {
+        let answer =
+            problem1_solution(&INPUT_1.lines().map(|s| s.to_owned()).collect_vec()).unwrap();
+        assert_eq!(answer, 2);
+    }
+
+    const INPUT_2: &str = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+
+    #[test]
+    fn undefined_node() {
+        let input = INPUT_1.replace("GGG = (GGG, GGG)", "GGG = (GGG,HHH)");
+        let error = parse(&input.lines().map(|s| s.to_owned()).collect_vec()).unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.text.as_str()),
+            (8, 12, "HHH")
+        );
+    }
+
+    #[test]
+    fn problem2() {
+        let answer =
+            problem2_solution(&INPUT_2.lines().map(|s| s.to_owned()).collect_vec()).unwrap();
+        assert_eq!(answer, 6);
+    }
+
+    #[test]
+    fn solves_generated_networks() {
+        for (seed, size) in [(0, 1), (1, 10), (2, 45)] {
+            let input = generators::day08(&mut Rng::new(seed), size);
+            assert!(assumptions(&input).unwrap().iter().all(Assumption::holds));
+            assert_eq!(problem1_solution(&input).unwrap() % size, 0);
+            problem2_solution(&input).unwrap();
+        }
+    }
+
+    #[test]
+    fn checks_assumptions() {
+        let input = INPUT_2
+            .replace("22B = (22C, 22C)", "22B = (22Z, 22Z)")
+            .lines()
+            .map(|s| s.to_owned())
+            .collect_vec();
+        assert!(assumptions(&input).unwrap().iter().all(Assumption::holds));
+
+        // the example's answer is only right by chance: 22A's cycle is 6 long, but it's on 22Z
+        // every 3 steps
+        let input = (INPUT_2.to_owned() + "\n33A = (33Z, 33Z)\n33B = (33Z, 33Z)\n33Z = (33B, 33B)")
+            .lines()
+            .map(|s| s.to_owned())
+            .collect_vec();
+        let violations = assumptions(&input)
+            .unwrap()
+            .into_iter()
+            .map(|a| a.violation)
+            .collect_vec();
+        assert_eq!(
+            violations,
+            [
+                None,
+                Some("the ghost from 22A passes 2 Z nodes in each cycle".to_owned()),
+                Some(
+                    "the ghost from 33A first reaches 33Z after 1 steps, but its cycle is 2 long"
+                        .to_owned()
+                )
+            ]
+        );
+    }
+}
@@ -0,0 +1,142 @@
+use anyhow::{bail, Result};
+use aoc_core::explain::Explanation;
+use aoc_core::input::{fold_lines, stream_lines, sum_lines, LineSource};
+use aoc_core::runner::Options;
+use num_traits::Zero;
+
+const INPUT_FILE: &str = "./data/2023/day09.txt";
+
+pub fn run(options: &Options) -> Result<()> {
+    println!(
+        "problem1 = {}",
+        problem_solution(stream_lines(options.input_file(INPUT_FILE))?, false)?
+    );
+    println!(
+        "problem2 = {}",
+        problem_solution(stream_lines(options.input_file(INPUT_FILE))?, true)?
+    );
+    options.print_explanation(|| explain(stream_lines(options.input_file(INPUT_FILE))?))
+}
+
+/// The pyramid of differences under each history, with the values each row is extended by at
+/// either end, working back up from the row of zeros.
+fn explain(input: impl LineSource) -> Result<Explanation> {
+    fold_lines(input, Explanation::default(), |mut explanation, line| {
+        let mut rows: Vec<Vec<i64>> = vec![line.numbers(line.text)?];
+        while !rows[rows.len() - 1].iter().all(i64::is_zero) {
+            let row = &rows[rows.len() - 1];
+            if row.len() < 2 {
+                bail!("Not a valid sequence");
+            }
+            rows.push(row.windows(2).map(|w| w[1] - w[0]).collect());
+        }
+        let (mut previous, mut next) = (0, 0);
+        let mut extended = vec![];
+        for row in rows.iter().rev() {
+            previous = row[0] - previous;
+            next += row[row.len() - 1];
+            extended.push((previous, next));
+        }
+        let section = explanation.section(
+            format!("history {}", line.index + 1),
+            &["depth", "previous", "values", "next"],
+        );
+        for (depth, (row, (previous, next))) in rows.iter().zip(extended.iter().rev()).enumerate() {
+            section.row([
+                depth.into(),
+                (*previous).into(),
+                row.clone().into(),
+                (*next).into(),
+            ]);
+        }
+        Ok(explanation)
+    })
+}
+
+fn problem_solution(input: impl LineSource, part2: bool) -> Result<i64> {
+    sum_lines(input, |line| extrapolate(line.numbers(line.text)?, part2))
+}
+
+/// Extends the sequence by one value at the end, or at the start for part 2. Each row of
+/// differences replaces the one before it, keeping just its last (or first) value on a stack, so
+/// long sequences need neither recursion nor a copy of every row.
+fn extrapolate(mut ns: Vec<i64>, part2: bool) -> Result<i64> {
+    let mut edges = vec![];
+    loop {
+        if ns.is_empty() {
+            bail!("Not a valid sequence");
+        } else if ns.iter().all(i64::is_zero) {
+            break;
+        }
+        edges.push(if part2 { ns[0] } else { ns[ns.len() - 1] });
+        for i in 1..ns.len() {
+            ns[i - 1] = ns[i] - ns[i - 1];
+        }
+        ns.pop();
+    }
+    Ok(edges.into_iter().rev().fold(
+        0,
+        |next, edge| if part2 { edge - next } else { edge + next },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::input::memory_lines;
+    use itertools::Itertools;
+
+    const INPUT: &str = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+
+    fn load_test_data() -> Vec<String> {
+        INPUT.lines().map(|s| s.to_owned()).collect()
+    }
+
+    #[test]
+    fn problem1() {
+        let answer = problem_solution(memory_lines(&load_test_data()), false).unwrap();
+        assert_eq!(answer, 114);
+    }
+
+    #[test]
+    fn problem2() {
+        let answer = problem_solution(memory_lines(&load_test_data()), true).unwrap();
+        assert_eq!(answer, 2);
+    }
+
+    #[test]
+    fn explains() {
+        let explanation = explain(memory_lines(&load_test_data())).unwrap();
+        let section = &explanation.sections[2];
+        assert_eq!(section.title, "history 3");
+        assert_eq!(section.rows.len(), 5);
+        let edges = section
+            .rows
+            .iter()
+            .map(|row| (row[1].as_i64().unwrap(), row[3].as_i64().unwrap()))
+            .collect_vec();
+        assert_eq!(edges, [(5, 68), (5, 23), (-2, 8), (2, 2), (0, 0)]);
+        assert!(explain(memory_lines(&["1 2 4 8"])).is_err());
+    }
+
+    #[test]
+    fn long_sequence() {
+        let cubic = |i: i64| i * i * i - 2 * i * i + 7;
+        let ns = (0..100_000).map(cubic).collect_vec();
+        assert_eq!(extrapolate(ns.clone(), false).unwrap(), cubic(100_000));
+        assert_eq!(extrapolate(ns, true).unwrap(), cubic(-1));
+        assert!(extrapolate(vec![1, 2, 4, 8], false).is_err());
+    }
+
+    #[test]
+    fn malformed_sequence() {
+        let input = ["0 3 6", "1 3 six"];
+        let error = problem_solution(memory_lines(&input), false).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 5: expected a number, found \"six\""
+        );
+    }
+}
@@ -0,0 +1,755 @@
+use anyhow::{anyhow, bail, Error as AnyError, Result};
+use aoc_core::assumptions::Assumption;
+use aoc_core::geometry::{interior_points, polygon_area2};
+use aoc_core::grid::Bounds;
+use aoc_core::grid::Direction::{self, Down, Left, Right, Up};
+use aoc_core::input::open;
+use aoc_core::parse::{lines, ParseError};
+use aoc_core::runner::{Options, Solver};
+use aoc_core::search;
+use itertools::Itertools;
+use std::collections::HashSet;
+use std::io::{self, BufRead, BufReader, Error, IsTerminal, Write};
+use std::str::FromStr;
+
+const INPUT_FILE: &str = "./data/2023/day10.txt";
+
+/// Solves the puzzle, counting enclosed tiles with the method named by the first argument
+/// (`flood`, `shoelace` or `scanline`, defaulting to `shoelace`). Given `from X Y`, also
+/// describes the loop through that tile. Given `viz`, draws the loop instead.
+pub fn run(options: &Options) -> Result<()> {
+    if options.positional == ["viz"] {
+        let lines = read_lines(options)?;
+        let stdout = io::stdout();
+        let colour = stdout.is_terminal();
+        render(&lines, &find_loop(&lines)?, colour, &mut stdout.lock())?;
+        return Ok(());
+    }
+    let (from, method) = match options.positional.as_slice() {
+        [from, x, y, rest @ ..] if from == "from" => (Some((x.parse()?, y.parse()?)), rest),
+        rest => (None, rest),
+    };
+    let method = match method {
+        [] => AreaMethod::Shoelace,
+        [name] => name.parse()?,
+        _ => bail!("Expected at most one method"),
+    };
+    let lines = read_lines(options)?;
+
+    if let Some(start) = from {
+        parse_grid(&lines)?;
+        let pipe_loop = trace_loop(&lines, start)?;
+        let (distance, farthest) = pipe_loop.farthest();
+        println!(
+            "loop from {:?} ({}): length {}, farthest {:?} at distance {}",
+            start,
+            pipe_loop.start_tile,
+            pipe_loop.length(),
+            farthest,
+            distance
+        );
+    }
+
+    println!("problem1 = {}", problem1_solution(&lines)?);
+    println!("problem2 = {}", problem2_solution(&lines, method)?);
+    Ok(())
+}
+
+fn read_lines(options: &Options) -> Result<Vec<String>> {
+    let input_file = open(options.input_file(INPUT_FILE))?;
+    Ok(BufReader::new(input_file)
+        .lines()
+        .collect::<Result<Vec<String>, Error>>()?)
+}
+
+pub fn check(options: &Options) -> Result<Vec<Assumption>> {
+    assumptions(&read_lines(options)?)
+}
+
+/// The loop is followed from the first S found, which is taken to be the only one, and to be a
+/// tile of pipe joining two of its neighbours.
+fn assumptions(grid: &[String]) -> Result<Vec<Assumption>> {
+    let start = parse_grid(grid)?;
+    let starts = grid
+        .iter()
+        .map(|line| line.matches('S').count())
+        .sum::<usize>();
+    let start_tile = infer_start_tile(grid, start);
+    let pipe_loop = match &start_tile {
+        Ok(_) => find_loop(grid).err().map(|e| e.to_string()),
+        Err(_) => Some("S isn't a pipe".to_owned()),
+    };
+    Ok(vec![
+        Assumption::check("there is exactly one S", starts == 1, || {
+            format!("there are {}", starts)
+        }),
+        Assumption::new(
+            "exactly two of S's neighbours connect to it",
+            start_tile.err().map(|e| e.to_string()),
+        ),
+        Assumption::new("the pipe through S closes into a loop", pipe_loop),
+    ])
+}
+
+/// Halving the loop or searching along it for the farthest tile, and each way of counting
+/// enclosed tiles, for `aoc diff`.
+pub static SOLVERS: &[Solver] = &[
+    Solver::new(1, "loop-length", |grid| {
+        Ok(problem1_solution(grid)?.to_string())
+    }),
+    Solver::new(1, "bfs", |grid| Ok(bfs_farthest(grid)?.to_string())),
+    Solver::new(2, "flood", |grid| {
+        Ok(problem2_solution(grid, AreaMethod::FloodFill)?.to_string())
+    }),
+    Solver::new(2, "shoelace", |grid| {
+        Ok(problem2_solution(grid, AreaMethod::Shoelace)?.to_string())
+    }),
+    Solver::new(2, "scanline", |grid| {
+        Ok(problem2_solution(grid, AreaMethod::Scanline)?.to_string())
+    }),
+];
+
+/// Ways of counting the tiles enclosed by the loop, which should all give the same answer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum AreaMethod {
+    /// flood fill from outside the loop, squeezing between pipes, and count what isn't reached
+    FloodFill,
+    /// the loop's area by the shoelace formula, then Pick's theorem for the tiles inside it
+    Shoelace,
+    /// scan along each row, switching between outside and inside at each pipe crossing the row
+    Scanline,
+}
+
+impl FromStr for AreaMethod {
+    type Err = AnyError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "flood" => Ok(AreaMethod::FloodFill),
+            "shoelace" => Ok(AreaMethod::Shoelace),
+            "scanline" => Ok(AreaMethod::Scanline),
+            _ => Err(anyhow!(
+                "Unknown method {}, expected flood, shoelace or scanline",
+                s
+            )),
+        }
+    }
+}
+
+fn problem1_solution(grid: &[String]) -> Result<usize> {
+    Ok(find_loop(grid)?.farthest().0)
+}
+
+/// The distance to the farthest tile of the loop found by searching breadth first from S along
+/// the pipes, rather than by going round the loop. Like [`find_loop`], it fails if any pipe it
+/// reaches doesn't connect back to both of its neighbours.
+fn bfs_farthest(grid: &[String]) -> Result<usize> {
+    let start = parse_grid(grid)?;
+    let start_tile = infer_start_tile(grid, start)?;
+    let bounds = Bounds::of(grid);
+    let tile = |pos| match tile_at(grid, pos) {
+        _ if pos == start => start_tile,
+        'S' => infer_start_tile(grid, pos).unwrap_or('.'),
+        tile => tile,
+    };
+    let connected = |pos| {
+        connections(tile(pos))
+            .into_iter()
+            .flatten()
+            .filter_map(move |d| {
+                let next = bounds.step(pos, d)?;
+                connections(tile(next))
+                    .is_some_and(|c| c.contains(&d.opposite()))
+                    .then_some(next)
+            })
+            .collect_vec()
+    };
+    let distances = search::distances(start, connected);
+    if let Some(pos) = distances.keys().find(|&&pos| connected(pos).len() < 2) {
+        bail!("The pipe at {:?} doesn't connect back", pos);
+    }
+    Ok(distances.into_values().max().unwrap_or_default())
+}
+
+fn problem2_solution(grid: &[String], method: AreaMethod) -> Result<usize> {
+    let pipe = find_loop(grid)?.tiles;
+    match method {
+        AreaMethod::FloodFill => {
+            let (horizontal_walls_below, vertical_walls_to_right_of) =
+                build_inner_walls(pipe).ok_or(anyhow!("Could not trace walls of loop"))?;
+            let outside_cells =
+                count_outside_cells(grid, &horizontal_walls_below, &vertical_walls_to_right_of);
+            Ok(grid.len() * grid[0].len() - outside_cells)
+        }
+        AreaMethod::Shoelace => Ok(shoelace_enclosed(&pipe)),
+        AreaMethod::Scanline => Ok(scanline_enclosed(grid, &pipe)),
+    }
+}
+
+/// A closed loop of pipe.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct PipeLoop {
+    /// positions of the loop's tiles in order, starting and ending with the same tile
+    tiles: Vec<(usize, usize)>,
+    /// the pipe shape of the first tile, which is inferred if it is `S`
+    start_tile: char,
+}
+
+impl PipeLoop {
+    fn length(&self) -> usize {
+        self.tiles.len() - 1
+    }
+
+    /// The tile or tiles furthest along the loop from its start, whichever way round the loop
+    /// you go, and how far away they are.
+    fn farthest(&self) -> (usize, Vec<(usize, usize)>) {
+        let length = self.length();
+        let tiles = [length / 2, length.div_ceil(2)]
+            .into_iter()
+            .dedup()
+            .map(|i| self.tiles[i])
+            .collect();
+        (length / 2, tiles)
+    }
+}
+
+/// Follows the loop through S.
+fn find_loop(grid: &[String]) -> Result<PipeLoop> {
+    trace_loop(grid, parse_grid(grid)?)
+}
+
+/// Follows the loop through the tile at `start`, failing if that tile isn't part of a loop.
+fn trace_loop(grid: &[String], start: (usize, usize)) -> Result<PipeLoop> {
+    let bounds = Bounds::of(grid);
+    if !bounds.contains(start) {
+        bail!(
+            "{:?} is outside the grid, which is {} by {}",
+            start,
+            bounds.width,
+            bounds.height
+        );
+    }
+    let start_tile = match tile_at(grid, start) {
+        'S' => infer_start_tile(grid, start)?,
+        tile => tile,
+    };
+    let [d, _] = connections(start_tile).ok_or(anyhow!("No pipe at {:?}", start))?;
+    let tiles = follow_pipe(grid, start, start_tile, d)?;
+    Ok(PipeLoop { tiles, start_tile })
+}
+
+/// Works out the pipe under `S` from which of its neighbours connect to it. This is ambiguous if
+/// more than two of them do.
+fn infer_start_tile(grid: &[String], pos: (usize, usize)) -> Result<char> {
+    let bounds = Bounds::of(grid);
+    let connected = Direction::ALL
+        .into_iter()
+        .filter(|&d| {
+            bounds
+                .step(pos, d)
+                .and_then(|n| connections(tile_at(grid, n)))
+                .is_some_and(|c| c.contains(&d.opposite()))
+        })
+        .collect_vec();
+    match connected.as_slice() {
+        &[a, b] => Ok(PIPE_TILES
+            .chars()
+            .find(|&t| connections(t).is_some_and(|c| c.contains(&a) && c.contains(&b)))
+            .unwrap_or_default()),
+        ds if ds.len() > 2 => bail!(
+            "The tile under S at {:?} is ambiguous, as {} neighbours connect to it ({:?})",
+            pos,
+            ds.len(),
+            ds
+        ),
+        ds => bail!(
+            "S at {:?} is not part of a loop, as only {} neighbours connect to it",
+            pos,
+            ds.len()
+        ),
+    }
+}
+
+/// Checks the grid is a rectangle of pipe tiles, returning the position of the start tile.
+fn parse_grid(grid: &[String]) -> Result<(usize, usize), ParseError> {
+    let width = grid.first().map(|line| line.len()).unwrap_or_default();
+    let mut start_pos = None;
+    for line in lines(grid) {
+        if let Some(x) = line.text.find(|c| !"|-LJ7F.S".contains(c)) {
+            let c = line.text[x..].chars().next().unwrap_or_default();
+            return Err(line.error(&line.text[x..x + c.len_utf8()], "a pipe tile"));
+        }
+        if line.text.len() != width {
+            return Err(line.error(line.text, &format!("{} tiles", width)));
+        }
+        if let Some(x) = line.text.find('S') {
+            start_pos = start_pos.or(Some((x, line.index)));
+        }
+    }
+    start_pos.ok_or(ParseError {
+        line: 1,
+        column: 1,
+        text: grid.first().cloned().unwrap_or_default(),
+        expected: "a start tile S somewhere in the grid".to_owned(),
+    })
+}
+
+/// Follows the pipe from `start` in direction `d`, until it gets back to `start`.
+fn follow_pipe(
+    grid: &[String],
+    start: (usize, usize),
+    start_tile: char,
+    d: Direction,
+) -> Result<Vec<(usize, usize)>> {
+    Bounds::of(grid).walk(start, d, |pos, d| {
+        let tile = match tile_at(grid, pos) {
+            _ if pos == start => start_tile,
+            'S' => infer_start_tile(grid, pos)?,
+            tile => tile,
+        };
+        let [a, b] = connections(tile)
+            .filter(|c| c.contains(&d.opposite()))
+            .ok_or(anyhow!(
+                "The pipe at {:?} doesn't connect back {:?}",
+                pos,
+                d.opposite()
+            ))?;
+        Ok(if pos == start {
+            None
+        } else if a == d.opposite() {
+            Some(b)
+        } else {
+            Some(a)
+        })
+    })
+}
+
+const PIPE_TILES: &str = "|-LJ7F";
+
+/// The directions a pipe tile connects in, or `None` if it isn't a pipe.
+fn connections(tile: char) -> Option<[Direction; 2]> {
+    match tile {
+        '|' => Some([Up, Down]),
+        '-' => Some([Left, Right]),
+        'L' => Some([Up, Right]),
+        'J' => Some([Up, Left]),
+        '7' => Some([Down, Left]),
+        'F' => Some([Down, Right]),
+        _ => None,
+    }
+}
+
+fn tile_at(grid: &[String], (x, y): (usize, usize)) -> char {
+    grid[y].as_bytes()[x] as char
+}
+
+type Walls = HashSet<(usize, usize)>;
+
+fn build_inner_walls(mut pipe: Vec<(usize, usize)>) -> Option<(Walls, Walls)> {
+    let mut horizontal_walls_below = HashSet::new();
+    let mut vertical_walls_to_right_of = HashSet::new();
+    let &top_left = pipe.iter().min()?;
+    let mut i = pipe.iter().position(|&c| c == top_left)?;
+    if pipe[i + 1].0 == top_left.0 {
+        pipe.reverse();
+        i = pipe.iter().position(|&c| c == top_left)?;
+    }
+    let mut in_d = Up;
+    for (curr, next) in pipe[i..].iter().chain(&pipe[1..=i]).tuple_windows() {
+        let dx = next.0 as isize - curr.0 as isize;
+        let dy = next.1 as isize - curr.1 as isize;
+        let out_d = Direction::ALL
+            .into_iter()
+            .find(|d| d.offset() == (dx, dy))?;
+        let walls = match (in_d, out_d) {
+            (Right, Right) => vec![Down],
+            (Left, Left) => vec![Up],
+            (Up, Up) => vec![Right],
+            (Down, Down) => vec![Left],
+            (Right, Up) => vec![Down, Right],
+            (Up, Left) => vec![Right, Up],
+            (Left, Down) => vec![Up, Left],
+            (Down, Right) => vec![Left, Down],
+            _ => vec![],
+        };
+        for w in walls {
+            match w {
+                Up => horizontal_walls_below.insert((curr.0, curr.1 - 1)),
+                Down => horizontal_walls_below.insert(*curr),
+                Left => vertical_walls_to_right_of.insert((curr.0 - 1, curr.1)),
+                Right => vertical_walls_to_right_of.insert(*curr),
+            };
+        }
+        in_d = out_d;
+    }
+    Some((horizontal_walls_below, vertical_walls_to_right_of))
+}
+
+/// Counts the cells reachable from the top left corner without crossing a wall.
+fn count_outside_cells(
+    grid: &[String],
+    horizontal_walls_below: &Walls,
+    vertical_walls_to_right_of: &Walls,
+) -> usize {
+    let bounds = Bounds::of(grid);
+    let outside = search::reachable((0, 0), |s| {
+        Direction::ALL.into_iter().filter_map(move |d| {
+            let n = bounds.step(s, d)?;
+            let is_blocked = match d {
+                Up => horizontal_walls_below.contains(&n),
+                Down => horizontal_walls_below.contains(&s),
+                Left => vertical_walls_to_right_of.contains(&n),
+                Right => vertical_walls_to_right_of.contains(&s),
+            };
+            (!is_blocked).then_some(n)
+        })
+    });
+    outside.len()
+}
+
+/// Treats the centres of the loop's tiles as the vertices of a polygon. Every loop tile is on its
+/// boundary, so the tiles enclosed are the lattice points strictly inside it.
+fn shoelace_enclosed(pipe: &[(usize, usize)]) -> usize {
+    let vertices = pipe
+        .iter()
+        .map(|&(x, y)| (x as isize, y as isize))
+        .collect_vec();
+    let boundary_points = pipe.len() as isize - 1;
+    interior_points(polygon_area2(&vertices), boundary_points) as usize
+}
+
+/// Scans each row just above the middle of its tiles, so that only loop tiles connecting upwards
+/// (`|`, `L` and `J`) cross the scanline.
+fn scanline_enclosed(grid: &[String], pipe: &[(usize, usize)]) -> usize {
+    let (_, inside) = scan_inside(grid, pipe);
+    inside.into_iter().filter(|&i| i).count()
+}
+
+/// Marks which tiles are on the loop, and which are enclosed by it, indexed by `y * width + x`.
+fn scan_inside(grid: &[String], pipe: &[(usize, usize)]) -> (Vec<bool>, Vec<bool>) {
+    let width = grid[0].len();
+    let mut on_loop = vec![false; width * grid.len()];
+    let mut connects_up = vec![false; width * grid.len()];
+    for (&a, &b) in pipe.iter().tuple_windows() {
+        on_loop[a.1 * width + a.0] = true;
+        if a.0 == b.0 {
+            let lower = a.max(b);
+            connects_up[lower.1 * width + lower.0] = true;
+        }
+    }
+    let mut inside = vec![false; width * grid.len()];
+    for y in 0..grid.len() {
+        let mut crossings = false;
+        for i in y * width..(y + 1) * width {
+            if on_loop[i] {
+                crossings = crossings != connects_up[i];
+            } else {
+                inside[i] = crossings;
+            }
+        }
+    }
+    (on_loop, inside)
+}
+
+const BOLD_YELLOW: &str = "\x1b[1;33m";
+const DIM_GREEN: &str = "\x1b[2;32m";
+const DIM_BLUE: &str = "\x1b[2;34m";
+const RESET: &str = "\x1b[0m";
+
+/// Draws the grid with box-drawing characters. In colour, the loop is highlighted and everything
+/// else is dimmed, green inside the loop and blue outside it. Without colour, tiles off the loop
+/// are shown as `I` or `O` for inside or outside, as in the puzzle text.
+fn render(
+    grid: &[String],
+    pipe_loop: &PipeLoop,
+    colour: bool,
+    out: &mut impl Write,
+) -> io::Result<()> {
+    let width = grid[0].len();
+    let (on_loop, inside) = scan_inside(grid, &pipe_loop.tiles);
+    let start = pipe_loop.tiles[0];
+    for (y, row) in grid.iter().enumerate() {
+        let mut line = String::new();
+        let mut style = "";
+        for (x, tile) in row.chars().enumerate() {
+            let i = y * width + x;
+            let tile = if (x, y) == start {
+                pipe_loop.start_tile
+            } else {
+                tile
+            };
+            let (tile_style, c) = match (on_loop[i], inside[i], colour) {
+                (true, _, _) => (BOLD_YELLOW, box_drawing(tile)),
+                (false, true, true) => (DIM_GREEN, box_drawing(tile)),
+                (false, false, true) => (DIM_BLUE, box_drawing(tile)),
+                (false, true, false) => ("", 'I'),
+                (false, false, false) => ("", 'O'),
+            };
+            if colour && tile_style != style {
+                line.push_str(tile_style);
+                style = tile_style;
+            }
+            line.push(c);
+        }
+        if colour {
+            line.push_str(RESET);
+        }
+        writeln!(out, "{}", line)?;
+    }
+    Ok(())
+}
+
+fn box_drawing(tile: char) -> char {
+    match tile {
+        '|' => '│',
+        '-' => '─',
+        'L' => '└',
+        'J' => '┘',
+        '7' => '┐',
+        'F' => '┌',
+        _ => '·',
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators;
+    use aoc_core::differential;
+    use aoc_core::random::Rng;
+
+    const PART1: &str = "-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF";
+
+    #[test]
+    fn problem1() {
+        let answer = problem1_solution(&grid(PART1)).unwrap();
+        assert_eq!(answer, 4);
+    }
+
+    #[test]
+    fn infers_start_tile() {
+        assert_eq!(find_loop(&grid(PART1)).unwrap().start_tile, 'F');
+        assert_eq!(find_loop(&grid(LARGER)).unwrap().start_tile, 'F');
+        assert_eq!(find_loop(&grid(PART2)).unwrap().start_tile, '7');
+
+        let error = find_loop(&grid(".|.\n-S-\n.|.")).unwrap_err();
+        assert!(error.to_string().contains("ambiguous"), "{}", error);
+        let error = find_loop(&grid("...\n-S.\n...")).unwrap_err();
+        assert!(error.to_string().contains("only 1 neighbours"), "{}", error);
+    }
+
+    #[test]
+    fn loops_from_any_tile() {
+        let pipe_loop = trace_loop(&grid(PART1), (3, 2)).unwrap();
+        assert_eq!(pipe_loop.length(), 8);
+        assert_eq!(pipe_loop.farthest(), (4, vec![(1, 2)]));
+
+        let two_loops = grid("F7.F-7\nLJ.S.|\n...L-J");
+        let pipe_loop = trace_loop(&two_loops, (0, 0)).unwrap();
+        assert_eq!(
+            pipe_loop.tiles,
+            vec![(0, 0), (0, 1), (1, 1), (1, 0), (0, 0)]
+        );
+        assert_eq!(pipe_loop.farthest(), (2, vec![(1, 1)]));
+        let pipe_loop = find_loop(&two_loops).unwrap();
+        assert_eq!(pipe_loop.start_tile, '|');
+        assert_eq!(pipe_loop.farthest(), (4, vec![(5, 1)]));
+
+        let error = trace_loop(&grid("F-7\n|.|\nL-J"), (1, 1)).unwrap_err();
+        assert_eq!(error.to_string(), "No pipe at (1, 1)");
+        let error = trace_loop(&grid(PART1), (999, 999)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "(999, 999) is outside the grid, which is 5 by 5"
+        );
+        assert!(trace_loop(&grid(PART1), (5, 0)).is_err());
+        assert!(trace_loop(&grid("F-7\n|.|\nL--"), (0, 0)).is_err());
+    }
+
+    /// A loop snaking back and forth across every tile of a grid, with S in the top left corner.
+    /// `height` must be even.
+    fn serpentine(width: usize, height: usize) -> Vec<String> {
+        (0..height)
+            .map(|y| {
+                let (first, second, last) = match y {
+                    0 => ('S', '-', '7'),
+                    _ if y == height - 1 => ('L', '-', 'J'),
+                    _ if y % 2 == 1 => ('|', 'F', 'J'),
+                    _ => ('|', 'L', '7'),
+                };
+                let mut row = String::with_capacity(width);
+                row.push(first);
+                row.push(second);
+                row.extend(std::iter::repeat_n('-', width - 3));
+                row.push(last);
+                row
+            })
+            .collect()
+    }
+
+    #[test]
+    fn very_long_loop() {
+        let grid = serpentine(3164, 3162);
+        let pipe_loop = find_loop(&grid).unwrap();
+        assert_eq!(pipe_loop.start_tile, 'F');
+        assert_eq!(pipe_loop.length(), 3164 * 3162);
+        assert!(pipe_loop.length() >= 10_000_000);
+        assert_eq!(pipe_loop.farthest(), (3164 * 3162 / 2, vec![(1583, 1581)]));
+    }
+
+    #[test]
+    fn renders_loop() {
+        let grid = grid(SQUEEZE);
+        let pipe_loop = find_loop(&grid).unwrap();
+        let mut out = vec![];
+        render(&grid, &pipe_loop, false, &mut out).unwrap();
+        let expected = "OOOOOOOOOO
+O┌──────┐O
+O│┌────┐│O
+O││OOOO││O
+O││OOOO││O
+O│└─┐┌─┘│O
+O│II││II│O
+O└──┘└──┘O
+OOOOOOOOOO
+";
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+
+        let mut out = vec![];
+        render(&grid, &pipe_loop, true, &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(
+            out.lines().nth(6).unwrap(),
+            "\x1b[2;34m·\x1b[1;33m│\x1b[2;32m··\x1b[1;33m││\x1b[2;32m··\x1b[1;33m│\x1b[2;34m·\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn unknown_tile() {
+        let error = parse_grid(&grid(&PART1.replace("L|7||", "L|7|X"))).unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (3, 5, "X"));
+    }
+
+    const PART2: &str = "FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L";
+
+    const SQUEEZE: &str = "..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........";
+
+    const LARGER: &str = ".F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...";
+
+    const METHODS: [AreaMethod; 3] = [
+        AreaMethod::FloodFill,
+        AreaMethod::Shoelace,
+        AreaMethod::Scanline,
+    ];
+
+    fn grid(text: &str) -> Vec<String> {
+        text.lines().map(|s| s.to_owned()).collect_vec()
+    }
+
+    #[test]
+    fn solves_generated_grids() {
+        let mut enclosed = vec![];
+        for (seed, size) in [(0, 4), (1, 9), (2, 30), (3, 5)] {
+            let grid = generators::day10(&mut Rng::new(seed), size);
+            assert!(assumptions(&grid).unwrap().iter().all(Assumption::holds));
+            let answers = METHODS.map(|method| problem2_solution(&grid, method).unwrap());
+            assert!(answers.iter().all_equal(), "{:?}", answers);
+            enclosed.push(answers[0]);
+        }
+        assert!(enclosed.iter().all(|&tiles| tiles > 0), "{:?}", enclosed);
+    }
+
+    #[test]
+    fn implementations_agree() {
+        let found = differential::search(SOLVERS, generators::day10, 12, 0, 30);
+        assert_eq!(found, None);
+        // loops enclosing junk, and squeezing between pipes
+        for (text, enclosed) in [(PART2, 10), (SQUEEZE, 4), (LARGER, 8)] {
+            let grid = grid(text);
+            assert_eq!(differential::compare(SOLVERS, &grid), None);
+            let area = problem2_solution(&grid, AreaMethod::FloodFill).unwrap();
+            assert_eq!(area, enclosed);
+        }
+        for text in [PART1, SQUEEZE, LARGER] {
+            let grid = grid(text);
+            assert_eq!(
+                bfs_farthest(&grid).unwrap(),
+                problem1_solution(&grid).unwrap()
+            );
+        }
+        assert!(bfs_farthest(&grid("S7.\n|L7\nL-.")).is_err());
+    }
+
+    #[test]
+    fn checks_assumptions() {
+        assert!(assumptions(&grid(LARGER))
+            .unwrap()
+            .iter()
+            .all(Assumption::holds));
+
+        let violations = |text| {
+            assumptions(&grid(text))
+                .unwrap()
+                .into_iter()
+                .map(|a| a.violation)
+                .collect_vec()
+        };
+        let found = violations("S7.\nLJ.\n..S");
+        assert_eq!(found[0].as_deref(), Some("there are 2"));
+        assert_eq!(found[1..], [None, None]);
+        let found = violations(".|.\n-S-\n.|.");
+        assert!(found[1].as_ref().unwrap().contains("ambiguous"));
+        assert_eq!(found[2].as_deref(), Some("S isn't a pipe"));
+        let found = violations("S7.\n|L7\nL-.");
+        assert!(found[2].as_ref().unwrap().contains("doesn't connect back"));
+    }
+
+    #[test]
+    fn problem2() {
+        for method in METHODS {
+            let answer = problem2_solution(&grid(PART2), method).unwrap();
+            assert_eq!(answer, 10, "{:?}", method);
+        }
+    }
+
+    #[test]
+    fn methods_agree() {
+        for (text, expected) in [(PART1, 1), (SQUEEZE, 4), (LARGER, 8)] {
+            for method in METHODS {
+                let answer = problem2_solution(&grid(text), method).unwrap();
+                assert_eq!(answer, expected, "{:?}", method);
+            }
+        }
+        assert!("bfs".parse::<AreaMethod>().is_err());
+    }
+}
@@ -0,0 +1,375 @@
+use anyhow::{anyhow, ensure, Result};
+use aoc_core::input::open;
+use aoc_core::parallel;
+use aoc_core::parse::{lines, ParseError};
+use aoc_core::runner::{Options, Solver};
+use itertools::Itertools;
+use num_traits::Zero;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::iter::Sum;
+use std::ops::{Add, Mul, Sub};
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, Error, Write},
+};
+
+const INPUT_FILE: &str = "./data/2023/day11.txt";
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+struct Params {
+    /// how many rows or columns each empty one becomes in part 2
+    expansion: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params { expansion: 1000000 }
+    }
+}
+
+/// Solves the puzzle. Given two galaxy numbers as arguments, also prints the distance between
+/// them, or given `render K [FILE]`, prints the universe expanded by a factor of K (or writes it
+/// to FILE).
+pub fn run(options: &Options) -> Result<()> {
+    let params: Params = options.day_params("day11")?;
+    ensure!(params.expansion > 0, "The expansion must be at least 1");
+    let input_file = open(options.input_file(INPUT_FILE))?;
+    let lines: Vec<String> = BufReader::new(input_file)
+        .lines()
+        .collect::<Result<Vec<String>, Error>>()?;
+
+    match options.positional.as_slice() {
+        [render, factor, output @ ..] if render == "render" && output.len() <= 1 => {
+            let universe = parse_universe(&lines)?;
+            let factor = factor.parse()?;
+            match output.first() {
+                Some(path) => universe.render(factor, &mut File::create(path)?)?,
+                None => universe.render(factor, &mut io::stdout().lock())?,
+            }
+            return Ok(());
+        }
+        [a, b] => {
+            let universe = parse_universe(&lines)?;
+            let galaxy_index = |number: &str| {
+                number
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|n| n.checked_sub(1))
+                    .filter(|&i| i < universe.galaxies.len())
+                    .ok_or(anyhow!("No galaxy {}", number))
+            };
+            let (a, b) = (galaxy_index(a)?, galaxy_index(b)?);
+            println!(
+                "galaxy {} to {} = {} / {}",
+                a + 1,
+                b + 1,
+                universe.distance(a, b, &2),
+                universe.distance(a, b, &params.expansion)
+            );
+        }
+        _ => {}
+    }
+
+    println!("problem1 = {}", solve(&lines, 2)?);
+    println!("problem2 = {}", solve(&lines, params.expansion)?);
+    Ok(())
+}
+
+/// Summing each pair's distance, and summing along each axis, for `aoc diff`. Part 2 uses the
+/// default expansion.
+pub static SOLVERS: &[Solver] = &[
+    Solver::new(1, "pairwise", |input| {
+        Ok(parse_universe(input)?.pairwise_total(&2).to_string())
+    }),
+    Solver::new(1, "prefix-sums", |input| Ok(solve(input, 2)?.to_string())),
+    Solver::new(2, "pairwise", |input| {
+        let factor = Params::default().expansion;
+        Ok(parse_universe(input)?.pairwise_total(&factor).to_string())
+    }),
+    Solver::new(2, "prefix-sums", |input| {
+        Ok(solve(input, Params::default().expansion)?.to_string())
+    }),
+];
+
+/// Sums the distances between every pair of galaxies, where each empty row or column counts as
+/// `factor` rows or columns.
+fn solve(input: &[String], factor: usize) -> Result<usize, ParseError> {
+    Ok(parse_universe(input)?.total_distance(&factor))
+}
+
+/// A number type which distances can be calculated in, such as `usize` or `BigInt`.
+trait Distance:
+    Clone + Zero + From<usize> + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+}
+
+impl<T> Distance for T where
+    T: Clone + Zero + From<usize> + Add<Output = T> + Sub<Output = T> + Mul<Output = T>
+{
+}
+
+struct Universe {
+    /// (x, y) positions, in the order the puzzle numbers them
+    galaxies: Vec<(usize, usize)>,
+    /// for each column, how many empty columns there are to its left, followed by the total
+    empty_cols_before: Vec<usize>,
+    /// for each row, how many empty rows there are above it, followed by the total
+    empty_rows_before: Vec<usize>,
+}
+
+impl Universe {
+    /// A galaxy's position once each empty row and column has been replaced by `factor` of them.
+    fn expanded<T: Distance>(&self, (x, y): (usize, usize), factor: &T) -> (T, T) {
+        let expand = |coord: usize, empty_before: usize| {
+            T::from(coord - empty_before) + factor.clone() * T::from(empty_before)
+        };
+        (
+            expand(x, self.empty_cols_before[x]),
+            expand(y, self.empty_rows_before[y]),
+        )
+    }
+
+    /// Distance between the galaxies at indexes `a` and `b` after expanding by `factor`.
+    fn distance<T: Distance>(&self, a: usize, b: usize, factor: &T) -> T {
+        let (ga, gb) = (self.galaxies[a], self.galaxies[b]);
+        let (ax, ay) = self.expanded(ga, factor);
+        let (bx, by) = self.expanded(gb, factor);
+        let dx = if ga.0 < gb.0 { bx - ax } else { ax - bx };
+        let dy = if ga.1 < gb.1 { by - ay } else { ay - by };
+        dx + dy
+    }
+
+    /// Sums the distance between every pair of galaxies one pair at a time, which is quadratic in
+    /// the number of galaxies but obviously right. With the `parallel` feature, each galaxy's
+    /// pairs are summed on whichever thread is free.
+    fn pairwise_total<T: Distance + Sum + Send + Sync>(&self, factor: &T) -> T {
+        parallel::sum_indexed(&self.galaxies, |a, _| {
+            (a + 1..self.galaxies.len())
+                .map(|b| self.distance(a, b, factor))
+                .sum()
+        })
+    }
+
+    /// Sum of distances between every pair of galaxies after expanding by `factor`. Along each
+    /// axis, the i-th smallest coordinate is further than each of the i coordinates before it,
+    /// so contributes `i * c[i] - (c[0] + ... + c[i - 1])`.
+    fn total_distance<T: Distance>(&self, factor: &T) -> T {
+        let expanded = self
+            .galaxies
+            .iter()
+            .map(|&g| self.expanded(g, factor))
+            .collect_vec();
+        let axis_total = |mut coords: Vec<(usize, T)>| {
+            coords.sort_by_key(|(original, _)| *original);
+            let (total, _) = coords.into_iter().enumerate().fold(
+                (T::zero(), T::zero()),
+                |(total, prefix), (i, (_, c))| {
+                    (total + c.clone() * T::from(i) - prefix.clone(), prefix + c)
+                },
+            );
+            total
+        };
+        let xs = self.galaxies.iter().zip(&expanded);
+        let ys = self.galaxies.iter().zip(&expanded);
+        axis_total(xs.map(|(g, e)| (g.0, e.0.clone())).collect())
+            + axis_total(ys.map(|(g, e)| (g.1, e.1.clone())).collect())
+    }
+
+    /// Writes the universe after expanding by `factor`, with each galaxy shown as its number so
+    /// it can be checked against the puzzle text. Cells are widened to fit the largest number.
+    fn render(&self, factor: usize, out: &mut impl Write) -> io::Result<()> {
+        let numbers: HashMap<(usize, usize), usize> = self
+            .galaxies
+            .iter()
+            .enumerate()
+            .map(|(i, &g)| (g, i + 1))
+            .collect();
+        let cell_width = self.galaxies.len().to_string().len();
+        let repeats = |empty_before: &[usize], i: usize| {
+            if empty_before[i + 1] > empty_before[i] {
+                factor
+            } else {
+                1
+            }
+        };
+        for y in 0..self.empty_rows_before.len() - 1 {
+            let row = (0..self.empty_cols_before.len() - 1)
+                .flat_map(|x| {
+                    let cell = match numbers.get(&(x, y)) {
+                        Some(number) => format!("{:>width$}", number, width = cell_width),
+                        None => ".".repeat(cell_width),
+                    };
+                    itertools::repeat_n(cell, repeats(&self.empty_cols_before, x))
+                })
+                .collect::<String>();
+            for _ in 0..repeats(&self.empty_rows_before, y) {
+                writeln!(out, "{}", row)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn parse_universe(input: &[String]) -> Result<Universe, ParseError> {
+    let width = input.first().map(|line| line.len()).unwrap_or_default();
+    let mut empty_cols = vec![true; width];
+    let mut galaxies = vec![];
+    let empty_rows = lines(input)
+        .map(|line| {
+            if let Some((x, c)) = line
+                .text
+                .char_indices()
+                .find(|&(_, c)| c != '.' && c != '#')
+            {
+                return Err(line.error(&line.text[x..x + c.len_utf8()], "'.' or '#'"));
+            }
+            if line.text.len() != width {
+                return Err(line.error(line.text, &format!("{} cells", width)));
+            }
+            Ok(line
+                .text
+                .chars()
+                .positions(|c| c == '#')
+                .fold(true, |_, x| {
+                    empty_cols[x] = false;
+                    galaxies.push((x, line.index));
+                    false
+                }))
+        })
+        .collect::<Result<Vec<bool>, ParseError>>()?;
+    let count_before = |empty: Vec<bool>| {
+        let mut counts = vec![0];
+        for is_empty in empty {
+            counts.push(counts[counts.len() - 1] + is_empty as usize);
+        }
+        counts
+    };
+    Ok(Universe {
+        galaxies,
+        empty_cols_before: count_before(empty_cols),
+        empty_rows_before: count_before(empty_rows),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators;
+    use aoc_core::differential;
+    use num_bigint::BigInt;
+
+    const INPUT: &str = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+    fn load_test_data() -> Vec<String> {
+        INPUT.lines().map(|s| s.to_owned()).collect()
+    }
+
+    #[test]
+    fn problem1() {
+        let answer = solve(&load_test_data(), 2).unwrap();
+        assert_eq!(answer, 374);
+    }
+
+    #[test]
+    fn problem2() {
+        let answer = solve(&load_test_data(), 10).unwrap();
+        assert_eq!(answer, 1030);
+    }
+
+    #[test]
+    fn matches_pairwise_total() {
+        let universe = parse_universe(&load_test_data()).unwrap();
+        for factor in [1, 2, 10, 100, 12345] {
+            assert_eq!(
+                universe.total_distance(&factor),
+                universe.pairwise_total(&factor)
+            );
+        }
+    }
+
+    #[test]
+    fn implementations_agree() {
+        let found = differential::search(SOLVERS, generators::day11, 20, 0, 30);
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    fn pair_distances() {
+        let universe = parse_universe(&load_test_data()).unwrap();
+        assert_eq!(universe.distance(4, 8, &2), 9);
+        assert_eq!(universe.distance(8, 4, &2), 9);
+        assert_eq!(universe.distance(0, 6, &2), 15);
+        assert_eq!(universe.distance(2, 5, &2), 17);
+        assert_eq!(universe.distance(7, 8, &2), 5);
+    }
+
+    #[test]
+    fn order_independent() {
+        let mut universe = parse_universe(&load_test_data()).unwrap();
+        universe.galaxies.reverse();
+        assert_eq!(universe.distance(4, 0, &2), 9);
+        assert_eq!(universe.distance(0, 4, &2), 9);
+        assert_eq!(universe.total_distance(&2), 374);
+        assert_eq!(universe.pairwise_total(&2), 374);
+    }
+
+    #[test]
+    fn render() {
+        let universe = parse_universe(&load_test_data()).unwrap();
+        let mut out = vec![];
+        universe.render(2, &mut out).unwrap();
+        let expected = "....1........
+.........2...
+3............
+.............
+.............
+........4....
+.5...........
+............6
+.............
+.............
+.........7...
+8....9.......
+";
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn threads_agree() {
+        let input = generators::day11(&mut aoc_core::random::Rng::new(5), 60);
+        let universe = parse_universe(&input).unwrap();
+        let pairwise = |threads| {
+            parallel::with_threads(threads, || universe.pairwise_total(&1000000)).unwrap()
+        };
+        assert_eq!(pairwise(1), pairwise(8));
+        assert_eq!(pairwise(1), universe.total_distance(&1000000));
+    }
+
+    #[test]
+    fn huge_expansion() {
+        let universe = parse_universe(&load_test_data()).unwrap();
+        let factor = BigInt::from(10).pow(30);
+        let expected = BigInt::from(210) + BigInt::from(82) * factor.clone();
+        assert_eq!(universe.total_distance(&factor), expected);
+    }
+
+    #[test]
+    fn ragged_rows() {
+        let mut input = load_test_data();
+        input[4].pop();
+        let error = solve(&input, 2).unwrap_err();
+        assert_eq!((error.line, error.expected.as_str()), (5, "10 cells"));
+    }
+}
@@ -0,0 +1,195 @@
+use anyhow::{ensure, Result};
+use aoc_core::input::{stream_lines, LineSource};
+use aoc_core::parallel::sum_lines;
+use aoc_core::parse::ParseError;
+use aoc_core::runner::Options;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+const INPUT_FILE: &str = "./data/2023/day12.txt";
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+struct Params {
+    /// how many copies of each row make up the unfolded row in part 2
+    unfold: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params { unfold: 5 }
+    }
+}
+
+pub fn run(options: &Options) -> Result<()> {
+    let params: Params = options.day_params("day12")?;
+    ensure!(params.unfold > 0, "Rows must unfold into at least 1 copy");
+    println!(
+        "problem1 = {}",
+        solve(stream_lines(options.input_file(INPUT_FILE))?, 1)?
+    );
+    println!(
+        "problem2 = {}",
+        solve(stream_lines(options.input_file(INPUT_FILE))?, params.unfold)?
+    );
+    Ok(())
+}
+
+/// Sums the arrangements of every row, after unfolding each into `unfold` copies of itself.
+fn solve<L>(input: L, unfold: usize) -> Result<usize>
+where
+    L: LineSource,
+    L::IntoIter: Send,
+    L::Text: Send,
+{
+    sum_lines(input, |line| {
+        let (lhs, rhs) = line.split_once(line.text, " ")?;
+        if let Some(i) = lhs.find(|c| !".#?".contains(c)) {
+            return Err(line.error(&lhs[i..], "'.', '#' or '?'").into());
+        }
+        let ns = rhs
+            .split(',')
+            .map(|n| line.parse(n, "a group length"))
+            .collect::<Result<Vec<usize>, ParseError>>()?;
+
+        let pattern = (0..unfold).map(|_| lhs).join("?");
+        let lengths = (0..unfold).flat_map(|_| &ns).copied().collect_vec();
+        Ok(feasible_count(&pattern, &lengths))
+    })
+}
+
+/// Where counting has got to: how much of the pattern and how many of the lengths are used up.
+type State = (usize, usize);
+
+/// Counts the ways the unknown springs in `pattern` can be filled in to give groups of `lengths`.
+/// The count from each state is a sum over the states it can move on to, so states are worked out
+/// depth first, with an explicit stack rather than recursion for the reason given at
+/// [`Bounds::walk`](aoc_core::grid::Bounds::walk).
+fn feasible_count(pattern: &str, lengths: &[usize]) -> usize {
+    let mut counts: HashMap<State, usize> = HashMap::new();
+    let mut stack = vec![(0, 0)];
+    while let Some(&state) = stack.last() {
+        let (finished, next) = moves(pattern, lengths, state);
+        let missing = next
+            .iter()
+            .flatten()
+            .filter(|s| !counts.contains_key(s))
+            .copied()
+            .collect_vec();
+        if missing.is_empty() {
+            let count = finished + next.iter().flatten().map(|s| counts[s]).sum::<usize>();
+            counts.insert(state, count);
+            stack.pop();
+        } else {
+            stack.extend(missing);
+        }
+    }
+    counts[&(0, 0)]
+}
+
+/// The ways of finishing straight away from a state (0 or 1), and the states it can move on to by
+/// skipping a working spring or placing the next group.
+fn moves(pattern: &str, lengths: &[usize], (i, j): State) -> (usize, [Option<State>; 2]) {
+    let rest = &pattern[i..];
+    if rest.is_empty() {
+        return ((j == lengths.len()) as usize, [None, None]);
+    }
+    if rest.starts_with('.') {
+        return (0, [Some((i + 1, j)), None]);
+    }
+    let &n = lengths.get(j).unwrap_or(&usize::MAX);
+    let mut finished = 0;
+    let mut place = None;
+    if rest.len() == n && j + 1 == lengths.len() {
+        finished = can_be_train(rest) as usize;
+    } else if rest.len() > n && can_be_train(&rest[..n]) && rest[n..].starts_with(['.', '?']) {
+        place = Some((i + n + 1, j + 1));
+    }
+    let skip = (!rest.starts_with('#')).then_some((i + 1, j));
+    (finished, [place, skip])
+}
+
+fn can_be_train(pattern: &str) -> bool {
+    pattern.chars().all(|c| c == '?' || c == '#')
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::generators;
+    use aoc_core::input::memory_lines;
+    use aoc_core::random::Rng;
+
+    const INPUT: &str = "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+
+    fn load_test_data() -> Vec<String> {
+        INPUT.lines().map(|s| s.to_owned()).collect()
+    }
+
+    #[test]
+    fn generated_rows_have_arrangements() {
+        for line in generators::day12(&mut Rng::new(3), 50) {
+            let (pattern, lengths) = line.split_once(' ').unwrap();
+            let lengths = lengths.split(',').map(|n| n.parse().unwrap()).collect_vec();
+            assert!(feasible_count(pattern, &lengths) > 0, "{}", line);
+        }
+    }
+
+    #[test]
+    fn feasible_count_test() {
+        assert_eq!(feasible_count(".#.##....###.####", &[1, 2, 3, 4]), 1);
+        assert_eq!(feasible_count(".#.##....###.####", &[1, 2, 4, 3]), 0);
+    }
+
+    #[test]
+    fn long_rows() {
+        assert_eq!(feasible_count(&"?".repeat(100_000), &[1]), 100_000);
+        assert_eq!(
+            feasible_count(&"?".repeat(100_000), &[1, 1]),
+            99_999 * 99_998 / 2
+        );
+        assert_eq!(feasible_count(&"#.".repeat(50_000), &[1; 50_000]), 1);
+        assert_eq!(feasible_count(&"#?".repeat(50_000), &[1; 49_999]), 0);
+    }
+
+    #[test]
+    fn bad_group_length() {
+        let input = ["???.### 1,x,3"];
+        let error = solve(memory_lines(&input), 1).unwrap_err();
+        let error = error.downcast_ref::<ParseError>().unwrap();
+        assert_eq!((error.column, error.text.as_str()), (11, "x"));
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn threads_agree() {
+        use aoc_core::parallel;
+
+        let input = generators::day12(&mut Rng::new(3), 200);
+        let solve_with = |threads| {
+            parallel::with_threads(threads, || solve(memory_lines(&input), 5).unwrap()).unwrap()
+        };
+        assert_eq!(solve_with(1), solve_with(8));
+    }
+
+    #[test]
+    fn problem1() {
+        let answer = solve(memory_lines(&load_test_data()), 1).unwrap();
+        assert_eq!(answer, 21);
+    }
+
+    #[test]
+    fn problem2() {
+        let answer = solve(memory_lines(&load_test_data()), 5).unwrap();
+        assert_eq!(answer, 525152);
+        let answer = solve(memory_lines(&["???.### 1,1,3"]), 2).unwrap();
+        assert_eq!(answer, 1);
+    }
+}
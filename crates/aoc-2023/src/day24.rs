@@ -0,0 +1,579 @@
+use anyhow::{anyhow, bail, Result};
+use aoc_core::geometry::Vec3;
+use aoc_core::input::open;
+use aoc_core::linear;
+use aoc_core::parse::{lines, Line, ParseError};
+use aoc_core::runner::Options;
+use itertools::Itertools;
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{Signed, ToPrimitive, Zero};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::io::{BufRead, BufReader, Error};
+
+const INPUT_FILE: &str = "./data/2023/day24.txt";
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+struct Params {
+    /// the least and greatest x and y of the test area in part 1
+    test_area: (isize, isize),
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            test_area: (200000000000000, 400000000000000),
+        }
+    }
+}
+
+/// Solves the puzzle. Given `pair I J`, also describes how the hailstones on lines I and J
+/// meet, given `pairs`, counts how each pair of hailstones meets, or given `times`, lists when
+/// the rock hits each hailstone.
+pub fn run(options: &Options) -> Result<()> {
+    let params: Params = options.day_params("day24")?;
+    let input_file = open(options.input_file(INPUT_FILE))?;
+    let lines: Vec<String> = BufReader::new(input_file)
+        .lines()
+        .collect::<Result<Vec<String>, Error>>()?;
+
+    match options.positional.as_slice() {
+        [pair, i, j] if pair == "pair" => {
+            let hailstones = parse_input(&lines)?;
+            let hailstone = |line: &str| {
+                line.parse::<usize>()
+                    .ok()
+                    .and_then(|n| hailstones.get(n.checked_sub(1)?))
+                    .copied()
+                    .ok_or(anyhow!("No hailstone on line {}", line))
+            };
+            println!("{}", encounter(hailstone(i)?, hailstone(j)?));
+        }
+        [pairs] if pairs == "pairs" => {
+            let counts = parse_input(&lines)?
+                .into_iter()
+                .tuple_combinations()
+                .map(|(a, b)| match encounter(a, b) {
+                    Encounter::Parallel => "parallel",
+                    Encounter::Coincident => "coincident",
+                    Encounter::Skew => "skew",
+                    Encounter::CrossedInPast { .. } => "crossed in the past",
+                    Encounter::CrossInFuture { .. } => "cross in the future",
+                    Encounter::Collide { .. } => "collide",
+                })
+                .counts();
+            for (kind, count) in counts.into_iter().sorted() {
+                println!("{:>8} {}", count, kind);
+            }
+        }
+        [times] if times == "times" => {
+            let throw = throw_rock(&parse_input(&lines)?)?;
+            println!("rock = {} @ {}", throw.position, throw.velocity);
+            for (i, time) in throw.times.iter().enumerate() {
+                println!("line {:>4} at time {}", i + 1, time);
+            }
+        }
+        [] => {}
+        _ => bail!("Expected `pair I J`, `pairs` or `times`"),
+    }
+
+    println!(
+        "problem1 = {}",
+        problem1_solution(&lines, params.test_area.0, params.test_area.1)?
+    );
+    println!("problem2 = {}", problem2_solution(&lines)?);
+    Ok(())
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct Hailstone {
+    position: Vec3<isize>,
+    velocity: Vec3<isize>,
+}
+
+fn problem1_solution(input: &[String], min: isize, max: isize) -> Result<usize> {
+    let parsed = parse_input(input)?;
+    // only the paths in the XY plane matter
+    let flatten = |v: Vec3<isize>| Vec3::new(v.x, v.y, 0).to_bigint();
+
+    let crossover_count = parsed
+        .iter()
+        .tuple_combinations()
+        .filter(|&(l, r)| {
+            // crossover point is where      lp + t * lv = rp + u * rv
+            // crossing both sides with rv:  (lp - rp) × rv + t * (lv × rv) = 0
+            //                           =>  t = (rv × (lp - rp)).z / (lv × rv).z
+            // and likewise                  u = (lv × (lp - rp)).z / (lv × rv).z
+            let (lp, lv) = (flatten(l.position), flatten(l.velocity));
+            let (rp, rv) = (flatten(r.position), flatten(r.velocity));
+            let delta = lp.clone() - rp;
+            let mut l_numer = rv.cross(&delta).z;
+            let mut r_numer = lv.cross(&delta).z;
+            let mut denom = lv.cross(&rv).z;
+            if denom.is_negative() {
+                l_numer = -l_numer;
+                r_numer = -r_numer;
+                denom = -denom;
+            }
+            let future_for_l = l_numer.is_positive();
+            let future_for_r = r_numer.is_positive();
+            // the crossover point, scaled up by denom to keep it whole
+            let crossover = lp.scale(&denom) + lv.scale(&l_numer);
+            let range = (&denom * min)..=(&denom * max);
+
+            future_for_l
+                && future_for_r
+                && range.contains(&crossover.x)
+                && range.contains(&crossover.y)
+        })
+        .count();
+
+    Ok(crossover_count)
+}
+
+fn problem2_solution(input: &[String]) -> Result<isize> {
+    let throw = throw_rock(&parse_input(input)?)?;
+    let total = throw.position.x + throw.position.y + throw.position.z;
+    total
+        .to_isize()
+        .ok_or(anyhow!("The answer {} is too big", total))
+}
+
+/// How many of the first hailstones to choose subsets from when finding the rock.
+const SUBSET_POOL: usize = 12;
+
+/// A rock which hits every hailstone, and when it hits each one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Throw {
+    position: Vec3<BigInt>,
+    velocity: Vec3<BigInt>,
+    times: Vec<BigRational>,
+}
+
+/// Finds the rock which hits every hailstone. Any three hailstones usually pin it down, but not
+/// if, say, their velocities are parallel. So triples are tried in turn, starting with those
+/// whose velocities are furthest from parallel to each other, until one gives a rock which hits
+/// all the hailstones.
+fn throw_rock(hailstones: &[Hailstone]) -> Result<Throw> {
+    if hailstones.len() < 3 {
+        bail!("Not enough input data");
+    }
+    let pool = &hailstones[..hailstones.len().min(SUBSET_POOL)];
+    let triples = pool
+        .iter()
+        .tuple_combinations()
+        .map(|(&a, &b, &c)| (spread(a, b, c), a, b, c))
+        .filter(|(spread, ..)| !spread.is_zero())
+        .sorted_by(|l, r| r.0.cmp(&l.0))
+        .collect_vec();
+
+    let mut last_error = anyhow!("The hailstones' velocities are all parallel");
+    for &(_, a, b, c) in &triples {
+        match find_rock(a, b, c).and_then(|(p, v)| check_rock(hailstones, p, v)) {
+            Ok(throw) => return Ok(throw),
+            Err(error) => last_error = error,
+        }
+    }
+    Err(last_error.context(format!(
+        "Tried {} subsets of hailstones, but no rock hits them all",
+        triples.len()
+    )))
+}
+
+/// How far from parallel the velocities of three hailstones are relative to each other, as the
+/// squared area of the parallelogram they make.
+fn spread(a: Hailstone, b: Hailstone, c: Hailstone) -> BigInt {
+    let v = a.velocity.to_bigint();
+    let normal = (b.velocity.to_bigint() - v.clone()).cross(&(c.velocity.to_bigint() - v));
+    normal.dot(&normal)
+}
+
+/// Checks the rock hits every hailstone, and works out when.
+fn check_rock(
+    hailstones: &[Hailstone],
+    position: Vec3<BigInt>,
+    velocity: Vec3<BigInt>,
+) -> Result<Throw> {
+    let times = hailstones
+        .iter()
+        .enumerate()
+        .map(|(i, &hailstone)| {
+            collision_time(&position, &velocity, hailstone).ok_or(anyhow!(
+                "The rock {} @ {} misses the hailstone on line {}",
+                position,
+                velocity,
+                i + 1
+            ))
+        })
+        .collect::<Result<Vec<BigRational>>>()?;
+    Ok(Throw {
+        position,
+        velocity,
+        times,
+    })
+}
+
+/// Finds the rock which hits all three hailstones. Its path crosses each hailstone's, so
+/// `(p - p_i) × (v - v_i) = 0`. Expanding this, the only non-linear term `p × v` is the same for
+/// every hailstone, so subtracting the equations for a pair of hailstones leaves linear ones:
+/// `p × (v_i - v_j) + (p_i - p_j) × v = p_i × v_i - p_j × v_j`. Two pairs give six equations for
+/// the six unknowns, which are solved exactly.
+fn find_rock(a: Hailstone, b: Hailstone, c: Hailstone) -> Result<(Vec3<BigInt>, Vec3<BigInt>)> {
+    let mut matrix = vec![];
+    let mut rhs = vec![];
+    for (i, j) in [(a, b), (a, c)] {
+        let (ip, iv) = (i.position.to_bigint(), i.velocity.to_bigint());
+        let (jp, jv) = (j.position.to_bigint(), j.velocity.to_bigint());
+        // p × dv and dp × v, written out as coefficients of p and v
+        let Vec3 {
+            x: ax,
+            y: ay,
+            z: az,
+        } = iv.clone() - jv.clone();
+        let Vec3 {
+            x: bx,
+            y: by,
+            z: bz,
+        } = ip.clone() - jp.clone();
+        let zero = BigInt::zero();
+        matrix.push(
+            [&zero, &az, &-&ay, &zero, &-&bz, &by]
+                .map(rational)
+                .to_vec(),
+        );
+        matrix.push(
+            [&-&az, &zero, &ax, &bz, &zero, &-&bx]
+                .map(rational)
+                .to_vec(),
+        );
+        matrix.push(
+            [&ay, &-&ax, &zero, &-&by, &bx, &zero]
+                .map(rational)
+                .to_vec(),
+        );
+        let products = ip.cross(&iv) - jp.cross(&jv);
+        rhs.extend(<[BigInt; 3]>::from(products).iter().map(rational));
+    }
+
+    let solution = linear::solve(matrix, rhs)
+        .ok_or(anyhow!("These hailstones don't pin down a single rock"))?;
+    if let Some(x) = solution.iter().find(|x| !x.is_integer()) {
+        bail!(
+            "The rock would need a fractional position or velocity {}",
+            x
+        );
+    }
+    let mut whole = solution.into_iter().map(|x| x.to_integer());
+    let mut next = || whole.next().unwrap();
+    Ok((
+        Vec3::new(next(), next(), next()),
+        Vec3::new(next(), next(), next()),
+    ))
+}
+
+/// When the rock hits the hailstone, if it ever does.
+fn collision_time(
+    position: &Vec3<BigInt>,
+    velocity: &Vec3<BigInt>,
+    hailstone: Hailstone,
+) -> Option<BigRational> {
+    let (p, v) = (
+        hailstone.position.to_bigint(),
+        hailstone.velocity.to_bigint(),
+    );
+    meeting_time(position, velocity, &p, &v).filter(|time| !time.is_negative())
+}
+
+/// The time, which may be negative, at which two objects moving in straight lines are in the same
+/// place, if there is one. Objects which are always together meet at time 0.
+fn meeting_time(
+    p1: &Vec3<BigInt>,
+    v1: &Vec3<BigInt>,
+    p2: &Vec3<BigInt>,
+    v2: &Vec3<BigInt>,
+) -> Option<BigRational> {
+    // p1 + t * v1 = p2 + t * v2  =>  p1 - p2 = t * (v2 - v1)
+    let gaps = p1.clone() - p2.clone();
+    let closing = v2.clone() - v1.clone();
+    let mut time: Option<BigRational> = None;
+    for (gap, closing) in <[BigInt; 3]>::from(gaps)
+        .into_iter()
+        .zip(<[BigInt; 3]>::from(closing))
+    {
+        if closing.is_zero() {
+            if !gap.is_zero() {
+                return None;
+            }
+        } else {
+            let t = BigRational::new(gap, closing);
+            if time.as_ref().is_some_and(|time| *time != t) {
+                return None;
+            }
+            time = Some(t);
+        }
+    }
+    Some(time.unwrap_or_else(BigRational::zero))
+}
+
+/// How the paths of two hailstones relate in 3D, from time 0 onwards.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Encounter {
+    /// the paths are parallel lines, which never meet
+    Parallel,
+    /// the paths are the same line, but the hailstones are never at the same place at once
+    Coincident,
+    /// the paths are neither parallel nor meet
+    Skew,
+    /// the paths cross, but at least one hailstone was there before time 0
+    CrossedInPast {
+        point: Vec3<BigRational>,
+        times: [BigRational; 2],
+    },
+    /// both hailstones will pass through the crossing point, at different times
+    CrossInFuture {
+        point: Vec3<BigRational>,
+        times: [BigRational; 2],
+    },
+    /// the hailstones will be at the same place at the same time
+    Collide {
+        point: Vec3<BigRational>,
+        time: BigRational,
+    },
+}
+
+impl Display for Encounter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Encounter::Parallel => write!(f, "parallel"),
+            Encounter::Coincident => write!(f, "coincident"),
+            Encounter::Skew => write!(f, "skew"),
+            Encounter::CrossedInPast { point, times } => write!(
+                f,
+                "crossed in the past at {}, at times {} and {}",
+                point, times[0], times[1]
+            ),
+            Encounter::CrossInFuture { point, times } => write!(
+                f,
+                "cross in the future at {}, at times {} and {}",
+                point, times[0], times[1]
+            ),
+            Encounter::Collide { point, time } => {
+                write!(f, "collide at {}, at time {}", point, time)
+            }
+        }
+    }
+}
+
+/// Works out how the paths of two hailstones relate, exactly.
+fn encounter(a: Hailstone, b: Hailstone) -> Encounter {
+    let (p1, v1) = (a.position.to_bigint(), a.velocity.to_bigint());
+    let (p2, v2) = (b.position.to_bigint(), b.velocity.to_bigint());
+    let at = |t: &BigRational| {
+        p1.clone().map(BigRational::from) + v1.clone().map(BigRational::from).scale(t)
+    };
+
+    if let Some(time) = meeting_time(&p1, &v1, &p2, &v2).filter(|t| !t.is_negative()) {
+        return Encounter::Collide {
+            point: at(&time),
+            time,
+        };
+    }
+
+    // p1 + t * v1 = p2 + s * v2  =>  t * v1 - s * v2 = gap. Crossing with v2 and v1 in turn
+    // gives t * (v1 × v2) = gap × v2 and s * (v1 × v2) = gap × v1.
+    let gap = p2 - p1.clone();
+    let normal = v1.cross(&v2);
+    let zero = Vec3::new(BigInt::zero(), BigInt::zero(), BigInt::zero());
+    if normal == zero {
+        return if gap.cross(&v1) == zero {
+            Encounter::Coincident
+        } else {
+            Encounter::Parallel
+        };
+    }
+    if !gap.dot(&normal).is_zero() {
+        return Encounter::Skew;
+    }
+    let norm2 = normal.dot(&normal);
+    let time = |v: &Vec3<BigInt>| BigRational::new(gap.cross(v).dot(&normal), norm2.clone());
+    let times = [time(&v2), time(&v1)];
+    let point = at(&times[0]);
+    if times.iter().any(Signed::is_negative) {
+        Encounter::CrossedInPast { point, times }
+    } else {
+        Encounter::CrossInFuture { point, times }
+    }
+}
+
+fn parse_input(input: &[String]) -> Result<Vec<Hailstone>, ParseError> {
+    lines(input)
+        .map(|line| {
+            let (p, v) = line.split_once(line.text, " @ ")?;
+            Ok(Hailstone {
+                position: parse_triple(&line, p)?,
+                velocity: parse_triple(&line, v)?,
+            })
+        })
+        .collect()
+}
+
+fn parse_triple(line: &Line, p: &str) -> Result<Vec3<isize>, ParseError> {
+    let ps = p
+        .split(',')
+        .map(|n| line.parse(n.trim(), "an integer"))
+        .collect::<Result<Vec<isize>, ParseError>>()?;
+    <[isize; 3]>::try_from(ps)
+        .map(Vec3::from)
+        .map_err(|_| line.error(p, "three comma separated integers"))
+}
+
+fn rational(x: &BigInt) -> BigRational {
+    BigRational::from(x.clone())
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::generators;
+    use aoc_core::random::Rng;
+
+    const INPUT: &str = "19, 13, 30 @ -2, 1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @ 1, -5, -3";
+
+    fn load_test_data() -> Vec<String> {
+        INPUT.lines().map(|s| s.to_owned()).collect()
+    }
+
+    #[test]
+    fn problem1() {
+        let answer = problem1_solution(&load_test_data(), 7, 27).unwrap();
+        assert_eq!(answer, 2);
+    }
+
+    #[test]
+    fn bad_triple() {
+        let error = parse_input(&["19, 13 @ -2, 1, -2".to_owned()]).unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (1, "19, 13"));
+    }
+
+    fn hailstone(text: &str) -> Hailstone {
+        parse_input(&[text.to_owned()]).unwrap()[0]
+    }
+
+    fn rationals(ns: [(i64, i64); 3]) -> Vec3<BigRational> {
+        Vec3::from(ns.map(|(n, d)| BigRational::new(n.into(), d.into())))
+    }
+
+    #[test]
+    fn encounters() {
+        let a = hailstone("0, 0, 0 @ 1, 0, 0");
+        assert_eq!(
+            encounter(
+                hailstone("0, 0, 0 @ 1, 1, 1"),
+                hailstone("2, 0, 0 @ 0, 1, 1")
+            ),
+            Encounter::Collide {
+                point: rationals([(2, 1), (2, 1), (2, 1)]),
+                time: BigRational::from(BigInt::from(2)),
+            }
+        );
+        assert_eq!(
+            encounter(a, hailstone("3, 0, 0 @ -1, 0, 0")),
+            Encounter::Collide {
+                point: rationals([(3, 2), (0, 1), (0, 1)]),
+                time: BigRational::new(3.into(), 2.into()),
+            }
+        );
+        assert_eq!(
+            encounter(a, hailstone("5, -3, 0 @ 0, 1, 0")).to_string(),
+            "cross in the future at (5, 0, 0), at times 5 and 3"
+        );
+        assert_eq!(
+            encounter(a, hailstone("5, 3, 0 @ 0, 1, 0")).to_string(),
+            "crossed in the past at (5, 0, 0), at times 5 and -3"
+        );
+        assert_eq!(
+            encounter(a, hailstone("0, 1, 0 @ 2, 0, 0")),
+            Encounter::Parallel
+        );
+        assert_eq!(
+            encounter(a, hailstone("3, 0, 0 @ 2, 0, 0")),
+            Encounter::Coincident
+        );
+        assert_eq!(
+            encounter(a, hailstone("0, 1, 1 @ 0, 0, 1")),
+            Encounter::Skew
+        );
+
+        // the example's first two hailstones cross in the XY plane, but not in 3D
+        let hailstones = parse_input(&load_test_data()).unwrap();
+        assert_eq!(encounter(hailstones[0], hailstones[1]), Encounter::Skew);
+    }
+
+    #[test]
+    fn problem2() {
+        let answer = problem2_solution(&load_test_data()).unwrap();
+        assert_eq!(answer, 47);
+    }
+
+    #[test]
+    fn rock_must_hit_every_hailstone() {
+        let hailstones = parse_input(&load_test_data()).unwrap();
+        let (position, velocity) = find_rock(hailstones[0], hailstones[1], hailstones[2]).unwrap();
+        assert_eq!(position, Vec3::new(24, 13, 10).to_bigint());
+        assert_eq!(velocity, Vec3::new(-3, 1, 2).to_bigint());
+        let times = hailstones
+            .iter()
+            .map(|&h| collision_time(&position, &velocity, h).unwrap())
+            .collect_vec();
+        assert_eq!(times, [5, 3, 4, 6, 1].map(|t| rational(&BigInt::from(t))));
+
+        let mut input = load_test_data();
+        input.push("0, 0, 0 @ 1, 1, 1".to_owned());
+        let error = problem2_solution(&input).unwrap_err();
+        assert!(
+            error.to_string().ends_with("no rock hits them all"),
+            "{}",
+            error
+        );
+
+        let parallel = [
+            "0, 0, 0 @ 1, 1, 1",
+            "1, 0, 0 @ 1, 1, 1",
+            "0, 1, 0 @ 1, 1, 1",
+        ];
+        let input = parallel.map(String::from);
+        assert!(problem2_solution(&input).is_err());
+    }
+
+    #[test]
+    fn skips_degenerate_hailstones() {
+        // three hailstones with the same velocity, which the example's rock hits at times 1 to 3
+        let mut input = vec![
+            "20, 13, 11 @ 1, 1, 1".to_owned(),
+            "16, 13, 12 @ 1, 1, 1".to_owned(),
+            "12, 13, 13 @ 1, 1, 1".to_owned(),
+        ];
+        input.extend(load_test_data());
+        let throw = throw_rock(&parse_input(&input).unwrap()).unwrap();
+        assert_eq!(throw.position, Vec3::new(24, 13, 10).to_bigint());
+        let times = [1, 2, 3, 5, 3, 4, 6, 1].map(|t| rational(&BigInt::from(t)));
+        assert_eq!(throw.times, times);
+        assert_eq!(problem2_solution(&input).unwrap(), 47);
+    }
+
+    #[test]
+    fn finds_planted_rocks() {
+        for (seed, size) in [(0, 3), (1, 5), (2, 40)] {
+            let (input, rock) = generators::hailstones_with_rock(&mut Rng::new(seed), size);
+            let answer = problem2_solution(&input).unwrap();
+            assert_eq!(answer as i64, rock.iter().sum::<i64>());
+        }
+    }
+}
@@ -1,15 +1,13 @@
 use anyhow::{anyhow, Result};
+use aoc_core::input::open;
+use aoc_core::runner::Options;
 use itertools::Itertools;
-use std::{
-    fs::File,
-    io::{BufRead, BufReader, Error},
-    path::Path,
-};
+use std::io::{BufRead, BufReader, Error};
 
-const INPUT_FILE: &str = concat!("./data/", env!("CARGO_BIN_NAME"), ".txt");
+const INPUT_FILE: &str = "./data/2023/dayNN.txt";
 
-fn main() -> Result<()> {
-    let input_file = File::open(Path::new(INPUT_FILE))?;
+pub fn run(options: &Options) -> Result<()> {
+    let input_file = open(options.input_file(INPUT_FILE))?;
     let lines: Vec<String> = BufReader::new(input_file)
         .lines()
         .collect::<Result<Vec<String>, Error>>()?;
@@ -19,13 +17,13 @@ fn main() -> Result<()> {
     Ok(())
 }
 
-fn problem1_solution(input: &Vec<String>) -> Result<usize> {
-    Ok(input.into_iter().dedup().count())
+fn problem1_solution(input: &[String]) -> Result<usize> {
+    Ok(input.iter().dedup().count())
 }
 
-fn problem2_solution(input: &Vec<String>) -> Result<usize> {
+fn problem2_solution(input: &[String]) -> Result<usize> {
     input
-        .into_iter()
+        .iter()
         .dedup_with_count()
         .map(|tuple| tuple.0)
         .max()
@@ -37,7 +35,7 @@ mod tests {
 
     use super::*;
 
-    const INPUT: &'static str = "A
+    const INPUT: &str = "A
 A
 B
 B
@@ -0,0 +1,524 @@
+//! Random puzzle inputs for each day, from a seed and a size, for testing the solutions on more
+//! than the examples and the real inputs. Each keeps to the promises the real inputs make, such
+//! as the assumptions the days declare, so every input has an answer. What `size` means is up to
+//! each day: usually the number of lines, or the width of a grid.
+
+use aoc_core::grid::Direction::{self, Down, Left, Right, Up};
+use aoc_core::random::Rng;
+use itertools::Itertools;
+use std::collections::{HashSet, VecDeque};
+
+const DIGIT_NAMES: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// `size` lines of letters, digits and names of digits, each with at least one digit.
+pub fn day01(rng: &mut Rng, size: usize) -> Vec<String> {
+    (0..size)
+        .map(|_| {
+            let parts = 1 + rng.below(6);
+            let digit_part = rng.below(parts);
+            let mut line = String::new();
+            for part in 0..parts {
+                match if part == digit_part { 0 } else { rng.below(3) } {
+                    0 => line.push(digit(rng)),
+                    1 => line.push_str(DIGIT_NAMES[rng.below(DIGIT_NAMES.len())]),
+                    _ => (0..1 + rng.below(4)).for_each(|_| line.push(letter(rng))),
+                }
+            }
+            line
+        })
+        .collect()
+}
+
+/// `size` games, each of up to six draws of up to 20 cubes of each colour.
+pub fn day02(rng: &mut Rng, size: usize) -> Vec<String> {
+    (1..=size)
+        .map(|id| {
+            let draws = (0..1 + rng.below(6))
+                .map(|_| {
+                    let mut colours = vec!["red", "green", "blue"];
+                    rng.shuffle(&mut colours);
+                    colours.truncate(1 + rng.below(3));
+                    colours
+                        .iter()
+                        .map(|colour| format!("{} {}", 1 + rng.below(20), colour))
+                        .join(", ")
+                })
+                .join("; ");
+            format!("Game {}: {}", id, draws)
+        })
+        .collect()
+}
+
+/// A `size` by `size` engine schematic of numbers and symbols.
+pub fn day03(rng: &mut Rng, size: usize) -> Vec<String> {
+    const SYMBOLS: &[u8] = b"*#+$/@=%&-";
+    (0..size)
+        .map(|_| {
+            let mut row = vec![b'.'; size];
+            let mut x = 0;
+            while x < size {
+                if rng.chance(1, 4) {
+                    let digits = (1 + rng.below(3)).min(size - x) as u32;
+                    let n = rng.between(10i64.pow(digits - 1), 10i64.pow(digits) - 1);
+                    row[x..x + digits as usize].copy_from_slice(n.to_string().as_bytes());
+                    x += digits as usize;
+                    if x < size && rng.chance(1, 4) {
+                        row[x] = *rng.choose(SYMBOLS);
+                    }
+                } else if rng.chance(1, 8) {
+                    row[x] = *rng.choose(SYMBOLS);
+                }
+                x += 1;
+            }
+            String::from_utf8(row).unwrap()
+        })
+        .collect()
+}
+
+/// `size` scratchcards of 10 winning numbers and 25 numbers you have. No card wins copies past
+/// the end, and cards stop winning once there are a million copies of one, to keep the answer
+/// in range.
+pub fn day04(rng: &mut Rng, size: usize) -> Vec<String> {
+    const WINNING: usize = 10;
+    const HAVE: usize = 25;
+    let mut won_copies: VecDeque<usize> = VecDeque::new();
+    (0..size)
+        .map(|i| {
+            let copies = 1 + won_copies.pop_front().unwrap_or_default();
+            let room = (size - 1 - i).min(WINNING);
+            let matches = if copies > 1_000_000 || rng.chance(1, 2) {
+                0
+            } else {
+                rng.below(room + 1)
+            };
+            if won_copies.len() < matches {
+                won_copies.resize(matches, 0);
+            }
+            for won in won_copies.iter_mut().take(matches) {
+                *won += copies;
+            }
+
+            let mut numbers = (1..100).collect_vec();
+            rng.shuffle(&mut numbers);
+            let winning = &numbers[..WINNING];
+            let mut have = numbers[WINNING..WINNING + HAVE - matches].to_vec();
+            have.extend(rng.sample(WINNING, matches).iter().map(|&j| winning[j]));
+            rng.shuffle(&mut have);
+            let list = |ns: &[usize]| ns.iter().map(|n| format!("{:>2}", n)).join(" ");
+            format!("Card {:>3}: {} | {}", i + 1, list(winning), list(&have))
+        })
+        .collect()
+}
+
+const ALMANAC_KINDS: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+/// An almanac whose maps each have about `size` ranges, which don't overlap, among ids below
+/// `100 * size`. There are `size / 2 + 1` ranges of seeds, each fewer than `10 * size` long.
+pub fn day05(rng: &mut Rng, size: usize) -> Vec<String> {
+    let size = size.max(1);
+    let ids = 100 * size;
+    let seeds = (0..size / 2 + 1)
+        .map(|_| format!("{} {}", rng.below(ids), 1 + rng.below(10 * size)))
+        .join(" ");
+    let mut lines = vec![format!("seeds: {}", seeds)];
+    for (from, to) in ALMANAC_KINDS.iter().tuple_windows() {
+        // cut the ids into segments, some of which are mapped, and lay the mapped ones out
+        // again in another order, so that neither the sources nor the destinations overlap
+        let mut cuts = rng
+            .sample(ids - 1, 2 * size)
+            .iter()
+            .map(|c| c + 1)
+            .collect_vec();
+        cuts.insert(0, 0);
+        cuts.push(ids);
+        let sources = cuts
+            .iter()
+            .tuple_windows()
+            .map(|(&start, &end)| (start, end - start))
+            .filter(|_| rng.chance(3, 4))
+            .collect_vec();
+        let mut order = (0..sources.len()).collect_vec();
+        rng.shuffle(&mut order);
+        let mut destination = rng.below(ids / 2);
+        let mut ranges = vec![(0, 0, 0); sources.len()];
+        for i in order {
+            let (source, length) = sources[i];
+            ranges[i] = (destination, source, length);
+            destination += length + rng.below(3);
+        }
+        rng.shuffle(&mut ranges);
+        lines.push(String::new());
+        lines.push(format!("{}-to-{} map:", from, to));
+        lines.extend(ranges.iter().map(|(d, s, l)| format!("{} {} {}", d, s, l)));
+    }
+    lines
+}
+
+/// `size` races, up to 8, each of which can be won. Their times have eight digits between them
+/// at most, so that part 2's single race stays short.
+pub fn day06(rng: &mut Rng, size: usize) -> Vec<String> {
+    let races = size.clamp(1, 8);
+    let digits = (8 / races).clamp(1, 3) as u32;
+    let (times, records): (Vec<i64>, Vec<i64>) = (0..races)
+        .map(|_| {
+            let time = rng.between(10i64.pow(digits - 1).max(2), 10i64.pow(digits) - 1);
+            let best = (time / 2) * (time - time / 2);
+            (time, rng.between(0, best - 1))
+        })
+        .unzip();
+    let widths = times
+        .iter()
+        .zip(&records)
+        .map(|(t, d)| t.to_string().len().max(d.to_string().len()))
+        .collect_vec();
+    let row = |label: &str, ns: &[i64]| {
+        let columns = ns
+            .iter()
+            .zip(&widths)
+            .map(|(n, &width)| format!("{:>width$}", n, width = width + 2))
+            .join("");
+        format!("{:<9}{}", label, columns)
+    };
+    vec![row("Time:", &times), row("Distance:", &records)]
+}
+
+/// `size` hands of camel cards with bids up to 1000.
+pub fn day07(rng: &mut Rng, size: usize) -> Vec<String> {
+    const CARDS: &[u8] = b"23456789TJQKA";
+    (0..size)
+        .map(|_| {
+            // fewer kinds of card make for more pairs and better hands
+            let count = 2 + rng.below(4);
+            let kinds = rng.sample(CARDS.len(), count);
+            let hand = (0..5)
+                .map(|_| CARDS[*rng.choose(&kinds)] as char)
+                .collect::<String>();
+            format!("{} {}", hand, 1 + rng.below(1000))
+        })
+        .collect()
+}
+
+/// `size` moves, up to 100, and a network in which `1 + size / 20` ghosts, up to 6, each go
+/// round a cycle with a Z node at its end. Each cycle's length is a different prime multiple of
+/// the number of moves, as in the real inputs. Ghost 0 goes from AAA to ZZZ.
+pub fn day08(rng: &mut Rng, size: usize) -> Vec<String> {
+    const PRIMES: [usize; 8] = [2, 3, 5, 7, 11, 13, 17, 19];
+    let moves = size.clamp(1, 100);
+    let ghosts = (1 + size / 20).min(6);
+    let mut names = node_names(rng);
+    let mut ends = [('A', "AAA"), ('Z', "ZZZ")].map(|(end, first)| {
+        let mut names = rng.sample(26 * 26, 26 * 26).into_iter().map(move |i| {
+            let name = [b'A' + (i / 26) as u8, b'A' + (i % 26) as u8, end as u8];
+            String::from_utf8(name.to_vec()).unwrap()
+        });
+        let rest = names.by_ref().filter(|name| name != first).collect_vec();
+        [first.to_owned()].into_iter().chain(rest)
+    });
+    let mut nodes = vec![];
+    for prime in rng
+        .sample(PRIMES.len(), ghosts)
+        .into_iter()
+        .map(|i| PRIMES[i])
+    {
+        let start = ends[0].next().unwrap();
+        let end = ends[1].next().unwrap();
+        // the ghost goes through one of two nodes at each step along its cycle, depending on
+        // the move, except the end, which all paths go through
+        let steps = prime * moves;
+        let pairs = (1..steps)
+            .map(|_| [names.pop().unwrap(), names.pop().unwrap()])
+            .collect_vec();
+        let next = |step: usize, rng: &mut Rng| match pairs.get(step) {
+            Some([a, b]) if rng.chance(1, 2) => (a.clone(), b.clone()),
+            Some([a, b]) => (b.clone(), a.clone()),
+            None => (end.clone(), end.clone()),
+        };
+        nodes.push((start, next(0, rng)));
+        for (step, pair) in pairs.iter().enumerate() {
+            let (left, right) = next(step + 1, rng);
+            for name in pair {
+                nodes.push((name.clone(), (left.clone(), right.clone())));
+            }
+        }
+        nodes.push((end.clone(), next(0, rng)));
+    }
+    rng.shuffle(&mut nodes);
+    let moves = (0..moves)
+        .map(|_| if rng.chance(1, 2) { 'L' } else { 'R' })
+        .collect::<String>();
+    [moves, String::new()]
+        .into_iter()
+        .chain(
+            nodes
+                .into_iter()
+                .map(|(name, (left, right))| format!("{} = ({}, {})", name, left, right)),
+        )
+        .collect()
+}
+
+/// Every name a node could have which ends in neither A nor Z, shuffled.
+fn node_names(rng: &mut Rng) -> Vec<String> {
+    let mut names = (0..26 * 26 * 24)
+        .map(|i| {
+            let name = [
+                b'A' + (i / (26 * 24)) as u8,
+                b'A' + (i / 24 % 26) as u8,
+                b'B' + (i % 24) as u8,
+            ];
+            String::from_utf8(name.to_vec()).unwrap()
+        })
+        .collect_vec();
+    rng.shuffle(&mut names);
+    names
+}
+
+/// `size` histories of 21 values of polynomials of degree up to 6.
+pub fn day09(rng: &mut Rng, size: usize) -> Vec<String> {
+    (0..size)
+        .map(|_| {
+            let coefficients = (0..=rng.below(7)).map(|_| rng.between(-5, 5)).collect_vec();
+            let first = rng.between(-10, 10);
+            (first..first + 21)
+                .map(|x| coefficients.iter().rev().fold(0, |sum, c| sum * x + c))
+                .join(" ")
+        })
+        .collect()
+}
+
+/// A `size` by `size` grid of pipes, at least 5 by 5, with a single loop through S among junk
+/// pipes. The loop goes round the outside of a random tree of corridors, so it never touches
+/// itself. The corridors are 2 pixels wide, so that the loop encloses tiles (junk included),
+/// but the walls between them are 1 wide, so that the loop squeezes past itself.
+pub fn day10(rng: &mut Rng, size: usize) -> Vec<String> {
+    let size = size.max(5);
+    let cells = (size - 2) / 3;
+    let pixels = 2 * cells + 1;
+    // grow a tree from a random cell, with each cell and each link between two cells filling a
+    // pixel, until it covers between half and all of the cells
+    let mut filled = vec![vec![false; pixels]; pixels];
+    let target = (cells * cells).div_ceil(2) + rng.below(cells * cells / 2 + 1);
+    let start = (rng.below(cells), rng.below(cells));
+    let mut in_tree = HashSet::from([start]);
+    filled[2 * start.1 + 1][2 * start.0 + 1] = true;
+    let mut frontier = vec![];
+    let add_links = |frontier: &mut Vec<_>, (x, y): (usize, usize)| {
+        let neighbours = [
+            (x + 1, y),
+            (x, y + 1),
+            (x.wrapping_sub(1), y),
+            (x, y.wrapping_sub(1)),
+        ];
+        for (nx, ny) in neighbours {
+            if nx < cells && ny < cells {
+                frontier.push(((x, y), (nx, ny)));
+            }
+        }
+    };
+    add_links(&mut frontier, start);
+    while in_tree.len() < target && !frontier.is_empty() {
+        let (from, to) = frontier.swap_remove(rng.below(frontier.len()));
+        if in_tree.insert(to) {
+            filled[2 * to.1 + 1][2 * to.0 + 1] = true;
+            filled[from.1 + to.1 + 1][from.0 + to.0 + 1] = true;
+            add_links(&mut frontier, to);
+        }
+    }
+
+    // cells, and the links between them, are widened to 2 pixels; walls stay 1 wide
+    let widened = (0..pixels)
+        .flat_map(|p| std::iter::repeat_n(p, 1 + p % 2))
+        .collect_vec();
+    let width = widened.len();
+
+    // the pipe at each corner of the pixels follows the edges between filled and empty pixels
+    let is_filled = |x: usize, y: usize| {
+        x.checked_sub(1)
+            .zip(y.checked_sub(1))
+            .and_then(|(x, y)| Some(filled[*widened.get(y)?][*widened.get(x)?]))
+            .unwrap_or(false)
+    };
+    let offset = (rng.below(size - width), rng.below(size - width));
+    let mut grid = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| *rng.choose(b"|-LJ7F......") as char)
+                .collect_vec()
+        })
+        .collect_vec();
+    let mut pipe = vec![];
+    for y in 0..=width {
+        for x in 0..=width {
+            // the pixels up-left, up-right, down-left and down-right of the corner
+            let [ul, ur, dl, dr] =
+                [(x, y), (x + 1, y), (x, y + 1), (x + 1, y + 1)].map(|(x, y)| is_filled(x, y));
+            let connected = [
+                (Up, ul != ur),
+                (Down, dl != dr),
+                (Left, ul != dl),
+                (Right, ur != dr),
+            ]
+            .into_iter()
+            .filter_map(|(d, edge)| edge.then_some(d))
+            .collect_vec();
+            if let [a, b] = connected[..] {
+                grid[y + offset.1][x + offset.0] = pipe_tile(a, b);
+                pipe.push((x + offset.0, y + offset.1));
+            }
+        }
+    }
+
+    // S replaces a tile of the loop, and any junk next to it mustn't look connected to it
+    let (sx, sy) = *rng.choose(&pipe);
+    grid[sy][sx] = 'S';
+    for (nx, ny) in [
+        (sx + 1, sy),
+        (sx, sy + 1),
+        (sx.wrapping_sub(1), sy),
+        (sx, sy.wrapping_sub(1)),
+    ] {
+        if nx < size && ny < size && !pipe.contains(&(nx, ny)) {
+            grid[ny][nx] = '.';
+        }
+    }
+    grid.into_iter().map(String::from_iter).collect()
+}
+
+fn pipe_tile(a: Direction, b: Direction) -> char {
+    match (a, b) {
+        (Up, Down) => '|',
+        (Left, Right) => '-',
+        (Up, Right) => 'L',
+        (Up, Left) => 'J',
+        (Down, Left) => '7',
+        (Down, Right) => 'F',
+        _ => unreachable!("Directions come in the order up, down, left, right"),
+    }
+}
+
+/// A `size` by `size` image with galaxies scattered around, and some rows and columns empty.
+pub fn day11(rng: &mut Rng, size: usize) -> Vec<String> {
+    let empty_rows = rng.sample(size, size / 8 + 1);
+    let empty_cols = rng.sample(size, size / 8 + 1);
+    (0..size)
+        .map(|y| {
+            (0..size)
+                .map(|x| {
+                    let empty = empty_rows.contains(&y) || empty_cols.contains(&x);
+                    if !empty && rng.chance(1, 12) {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// `size` rows of springs, each made by hiding some of the springs of a row which fits its
+/// groups, so each row has at least one arrangement.
+pub fn day12(rng: &mut Rng, size: usize) -> Vec<String> {
+    (0..size)
+        .map(|_| {
+            let length = 3 + rng.below(18);
+            let mut springs = ".".repeat(rng.below(3));
+            let mut groups = vec![];
+            while springs.len() < length || groups.is_empty() {
+                let group = 1 + rng.below(4);
+                springs.push_str(&"#".repeat(group));
+                springs.push_str(&".".repeat(1 + rng.below(3)));
+                groups.push(group);
+            }
+            let springs = springs
+                .chars()
+                .map(|c| if rng.chance(1, 2) { '?' } else { c })
+                .collect::<String>();
+            format!("{} {}", springs, groups.iter().join(","))
+        })
+        .collect()
+}
+
+/// `size` hailstones, at least 3, all of which are hit by a rock thrown from a random position
+/// near the test area.
+pub fn day24(rng: &mut Rng, size: usize) -> Vec<String> {
+    hailstones_with_rock(rng, size).0
+}
+
+/// Hailstones, and the position of the rock which hits them all.
+pub(crate) fn hailstones_with_rock(rng: &mut Rng, size: usize) -> (Vec<String>, [i64; 3]) {
+    let rock = [0; 3].map(|_| rng.between(200_000_000_000_000, 400_000_000_000_000));
+    let rock_velocity = [0; 3].map(|_| rng.between(-300, 300));
+    let mut times = HashSet::new();
+    let hailstones = (0..size.max(3))
+        .map(|_| {
+            let time = loop {
+                let time = rng.between(100_000_000_000, 1_000_000_000_000);
+                if times.insert(time) {
+                    break time;
+                }
+            };
+            let velocity = loop {
+                let velocity = [0; 3].map(|_| rng.between(-300, 300));
+                if velocity != rock_velocity {
+                    break velocity;
+                }
+            };
+            // the hailstone is where the rock will be at `time`
+            let position = [0, 1, 2].map(|i| rock[i] + time * (rock_velocity[i] - velocity[i]));
+            format!(
+                "{}, {}, {} @ {}, {}, {}",
+                position[0], position[1], position[2], velocity[0], velocity[1], velocity[2]
+            )
+        })
+        .collect();
+    (hailstones, rock)
+}
+
+fn digit(rng: &mut Rng) -> char {
+    (b'1' + rng.below(9) as u8) as char
+}
+
+fn letter(rng: &mut Rng) -> char {
+    (b'a' + rng.below(26) as u8) as char
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeats_for_a_seed() {
+        for generate in [day03, day05, day08, day10, day24] {
+            let input = generate(&mut Rng::new(5), 20);
+            assert_eq!(input, generate(&mut Rng::new(5), 20));
+            assert_ne!(input, generate(&mut Rng::new(6), 20));
+        }
+    }
+
+    #[test]
+    fn sizes_inputs() {
+        let mut rng = Rng::new(1);
+        assert_eq!(day01(&mut rng, 7).len(), 7);
+        assert_eq!(day09(&mut rng, 7).len(), 7);
+        let grid = day10(&mut rng, 9);
+        assert_eq!(grid.len(), 9);
+        assert!(grid.iter().all(|row| row.len() == 9));
+        assert_eq!(grid.concat().matches('S').count(), 1);
+        assert_eq!(day11(&mut rng, 12).concat().len(), 144);
+        assert_eq!(day24(&mut rng, 1).len(), 3);
+        let races = day06(&mut rng, 4);
+        let kerned = races[0].chars().filter(char::is_ascii_digit).count();
+        assert_eq!(kerned, 8);
+    }
+}
@@ -0,0 +1,55 @@
+//! Solutions to [Advent of Code 2023](https://adventofcode.com/2023), a module per day.
+
+use aoc_core::runner::{Day, Year};
+
+/// Copied by `new_day.sh` to start each new day.
+#[doc(hidden)]
+pub mod day_template;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day24;
+pub mod generators;
+
+pub static YEAR: Year = Year::new(2023, DAYS);
+
+pub static DAYS: &[Day] = &[
+    Day::new(1, day01::run).with_generator(generators::day01),
+    Day::new(2, day02::run).with_generator(generators::day02),
+    Day::new(3, day03::run).with_generator(generators::day03),
+    Day::new(4, day04::run)
+        .with_check(day04::check)
+        .with_generator(generators::day04),
+    Day::new(5, day05::run)
+        .with_check(day05::check)
+        .with_generator(generators::day05)
+        .with_solvers(day05::SOLVERS),
+    Day::new(6, day06::run)
+        .with_generator(generators::day06)
+        .with_solvers(day06::SOLVERS),
+    Day::new(7, day07::run).with_generator(generators::day07),
+    Day::new(8, day08::run)
+        .with_check(day08::check)
+        .with_generator(generators::day08),
+    Day::new(9, day09::run).with_generator(generators::day09),
+    Day::new(10, day10::run)
+        .with_check(day10::check)
+        .with_generator(generators::day10)
+        .with_solvers(day10::SOLVERS),
+    Day::new(11, day11::run)
+        .with_generator(generators::day11)
+        .with_solvers(day11::SOLVERS),
+    Day::new(12, day12::run).with_generator(generators::day12),
+    Day::new(24, day24::run).with_generator(generators::day24),
+    // new_day.sh adds each new day here
+];
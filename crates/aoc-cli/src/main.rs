@@ -0,0 +1,293 @@
+use anyhow::{anyhow, bail, Result};
+use aoc_core::assumptions::write_report;
+use aoc_core::differential::{self, write_mismatch};
+use aoc_core::profiles::{self, check, parse_answers, write_summary, Answers, Outcome};
+use aoc_core::random::Rng;
+use aoc_core::runner::{find_year, Day, Options, Year};
+use aoc_core::sealed::{seal_dir, unseal_dir, Key, DATA_DIR};
+use std::panic;
+use std::path::Path;
+use std::process::{self, Command, Output};
+use std::{env, io};
+
+/// Every year with solutions.
+static YEARS: &[Year] = &[aoc_2023::YEAR];
+
+/// How big an input `aoc gen` makes without `--size`.
+const DEFAULT_SIZE: usize = 10;
+
+/// How many generated inputs `aoc diff` compares the implementations on.
+const DIFF_RUNS: usize = 100;
+
+/// Days which can draw their puzzle with `viz`, by year.
+const VIZ_DAYS: [(u32, u32); 1] = [(2023, 10)];
+
+/// Runs a day's solution, from the latest year unless given `--year YYYY`:
+///
+/// - `aoc run <day> [args]` solves the day's puzzle
+/// - `aoc run <day> --all-profiles [args]` solves it with every profile's input, and sums up
+///   the answers
+/// - `aoc viz <day>` draws it
+/// - `aoc gen <day> [--size N] [--seed S]` makes up an input for it
+/// - `aoc check <day>` reports which of the assumptions its solution makes about the input hold,
+///   for every profile's input if given `--all-profiles`
+/// - `aoc diff <day> [--size N] [--seed S]` compares its implementations of each part on made up
+///   inputs of up to size N, and shrinks any input they disagree on
+/// - `aoc data seal` encrypts every input in `data`, so that they can be committed, and `aoc data
+///   unseal` decrypts them again
+fn main() -> Result<()> {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+    if let [data, action] = args.as_slice() {
+        if data == "data" {
+            return data_command(action);
+        }
+    }
+    let (day, options) = day_command(&args)?;
+    if args[0] == "gen" {
+        return generate(day, &options);
+    }
+    if args[0] == "check" {
+        let holds = check_assumptions(day, options)?;
+        process::exit(!holds as i32);
+    }
+    if args[0] == "diff" {
+        let agreed = compare_solvers(day, &options)?;
+        process::exit(!agreed as i32);
+    }
+    if options.all_profiles {
+        let year = options.year.ok_or(anyhow!("No year chosen"))?;
+        let flagged = run_all_profiles(year, day.number, &args)?;
+        process::exit(flagged as i32);
+    }
+    (day.run)(&options.apply()?)
+}
+
+fn data_command(action: &str) -> Result<()> {
+    let written = match action {
+        "seal" => seal_dir(Path::new(DATA_DIR), &Key::load_or_create()?)?,
+        "unseal" => unseal_dir(Path::new(DATA_DIR), &Key::load()?)?,
+        _ => bail!("Usage: aoc data seal|unseal"),
+    };
+    for path in written {
+        println!("wrote {}", path.display());
+    }
+    Ok(())
+}
+
+/// Prints a made up input for a day.
+fn generate(day: &Day, options: &Options) -> Result<()> {
+    let generate = day
+        .generate
+        .ok_or(anyhow!("Day {} has no generator", day.number))?;
+    let mut rng = Rng::new(options.seed.unwrap_or_default());
+    for line in generate(&mut rng, options.size.unwrap_or(DEFAULT_SIZE)) {
+        println!("{}", line);
+    }
+    Ok(())
+}
+
+/// Compares each part's implementations on generated inputs, printing the first input they
+/// disagree on once shrunk. Returns whether they always agreed.
+fn compare_solvers(day: &Day, options: &Options) -> Result<bool> {
+    let generate = day
+        .generate
+        .ok_or(anyhow!("Day {} has no generator", day.number))?;
+    let parts = differential::parts(day.solvers);
+    if parts.is_empty() {
+        bail!(
+            "Day {} has only one implementation of each part",
+            day.number
+        );
+    }
+    for part in parts {
+        let names = day
+            .solvers
+            .iter()
+            .filter(|s| s.part == part)
+            .map(|s| s.name);
+        println!("part {}: {}", part, names.collect::<Vec<&str>>().join(", "));
+    }
+    // a panic is reported as a mismatch, and shrinking may cause many, so don't print them
+    panic::set_hook(Box::new(|_| {}));
+    let found = differential::search(
+        day.solvers,
+        generate,
+        options.size.unwrap_or(DEFAULT_SIZE),
+        options.seed.unwrap_or_default(),
+        DIFF_RUNS,
+    );
+    drop(panic::take_hook());
+    let Some(found) = found else {
+        println!("all agreed on {} inputs", DIFF_RUNS);
+        return Ok(true);
+    };
+    println!(
+        "mismatch with seed {} and size {}, shrunk to:",
+        found.seed, found.size
+    );
+    write_mismatch(&found.mismatch, &mut io::stdout().lock())?;
+    Ok(false)
+}
+
+/// Reports which of a day's assumptions hold for its input, or for each profile's input. Returns
+/// whether they all do.
+fn check_assumptions(day: &Day, options: Options) -> Result<bool> {
+    let year = options.year.ok_or(anyhow!("No year chosen"))?;
+    let check = day.check.ok_or(anyhow!(
+        "Day {} of {} declares no assumptions",
+        day.number,
+        year
+    ))?;
+    let options = options.apply()?;
+    if !options.all_profiles {
+        return Ok(write_report(&check(&options)?, &mut io::stdout().lock())?);
+    }
+    let mut all_hold = true;
+    for profile in profiles::profiles(&profiles::input_path(year, day.number))? {
+        println!("{}:", profile);
+        let options = Options {
+            profile: Some(profile),
+            ..options.clone()
+        };
+        all_hold &= write_report(&check(&options)?, &mut io::stdout().lock())?;
+    }
+    Ok(all_hold)
+}
+
+/// Runs a day once for each profile, each in a process of its own so that one failing doesn't
+/// stop the rest, then prints a summary. Returns whether any profile was flagged.
+fn run_all_profiles(year: u32, day: u32, args: &[String]) -> Result<bool> {
+    let default_input = profiles::input_path(year, day);
+    let names = profiles::profiles(&default_input)?;
+    if names.is_empty() {
+        bail!("No inputs for day {} of {}", day, year);
+    }
+    let answers = Answers::load(default_input.parent().unwrap())?;
+    let day_name = format!("day{:02}", day);
+    let exe = env::current_exe()?;
+    let mut outcomes = vec![];
+    for profile in names {
+        let output = Command::new(&exe)
+            .args(profile_args(args, year, &profile))
+            .output()?;
+        let outcome = profile_outcome(&output, answers.get(&profile, &day_name));
+        outcomes.push((profile, outcome));
+    }
+    Ok(write_summary(&outcomes, &mut io::stdout().lock())?)
+}
+
+/// The arguments to solve a day with one profile's input, given those to solve it with all.
+fn profile_args(args: &[String], year: u32, profile: &str) -> Vec<String> {
+    let mut profile_args = args
+        .iter()
+        .filter(|&arg| arg != "--all-profiles")
+        .cloned()
+        .collect::<Vec<String>>();
+    profile_args.extend([
+        "--year".to_owned(),
+        year.to_string(),
+        "--profile".to_owned(),
+        profile.to_owned(),
+    ]);
+    profile_args
+}
+
+fn profile_outcome(output: &Output, recorded: Option<&[String]>) -> Outcome {
+    if output.status.success() {
+        let answers = parse_answers(&String::from_utf8_lossy(&output.stdout));
+        let verdict = check(&answers, recorded);
+        Outcome::Solved { answers, verdict }
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let first_line = stderr.lines().next().unwrap_or_default();
+        let message = match first_line.strip_prefix("Error: ") {
+            Some(error) => error.to_owned(),
+            None => output.status.to_string(),
+        };
+        Outcome::Failed { message }
+    }
+}
+
+/// The day to run for a command line, and the options to run it with.
+fn day_command(args: &[String]) -> Result<(&'static Day, Options)> {
+    let [command, day, rest @ ..] = args else {
+        bail!("Usage: aoc run|viz|gen|check|diff <day> [args]");
+    };
+    let number: u32 = day.parse().map_err(|_| anyhow!("Not a day: {}", day))?;
+    let mut options = Options::parse(rest.iter().cloned())?;
+    let year = find_year(YEARS, options.year)?;
+    options.year = Some(year.year);
+    match command.as_str() {
+        "run" | "gen" | "check" | "diff" => {}
+        "viz" if VIZ_DAYS.contains(&(year.year, number)) => {
+            options.positional.insert(0, "viz".to_owned())
+        }
+        "viz" => bail!("Day {} of {} has no visualisation", number, year.year),
+        _ => bail!("Unknown command {}", command),
+    };
+    let day = year.day(number).ok_or(anyhow!(
+        "Day {} of {} hasn't been solved",
+        number,
+        year.year
+    ))?;
+    Ok((day, options))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(args: &[&str]) -> Result<(&'static Day, Options)> {
+        day_command(&args.iter().map(|&a| a.to_owned()).collect::<Vec<String>>())
+    }
+
+    #[test]
+    fn dispatches_to_days() {
+        let (day, options) = command(&["viz", "10"]).unwrap();
+        assert_eq!(day.number, 10);
+        assert_eq!(options.positional, vec!["viz"]);
+        assert_eq!(options.year, Some(2023));
+
+        let (day, options) = command(&["viz", "10", "--year", "2023"]).unwrap();
+        assert_eq!(day.number, 10);
+        assert_eq!(options.positional, vec!["viz"]);
+
+        let (day, options) = command(&["run", "7", "hands.txt", "--threads", "2"]).unwrap();
+        assert_eq!(day.number, 7);
+        assert_eq!(options.positional, vec!["hands.txt"]);
+        assert_eq!(options.threads, Some(2));
+
+        let (day, _) = command(&["check", "8"]).unwrap();
+        assert!(day.check.is_some());
+        let (day, options) = command(&["gen", "10", "--size", "20"]).unwrap();
+        assert!(day.generate.is_some());
+        assert_eq!(options.size, Some(20));
+        let (day, _) = command(&["diff", "6"]).unwrap();
+        assert!(!day.solvers.is_empty());
+        assert!(command(&["viz", "7"]).is_err());
+        assert!(command(&["run", "13"]).is_err());
+        assert!(command(&["run", "1", "--year", "2015"]).is_err());
+        assert!(command(&["run"]).is_err());
+        assert!(command(&["fly", "10"]).is_err());
+    }
+
+    #[test]
+    fn runs_each_profile() {
+        let args = ["run", "8", "--all-profiles", "--threads", "2"].map(String::from);
+        let (_, options) = day_command(&args).unwrap();
+        assert!(options.all_profiles);
+        assert_eq!(
+            profile_args(&args, 2023, "alice"),
+            [
+                "run",
+                "8",
+                "--threads",
+                "2",
+                "--year",
+                "2023",
+                "--profile",
+                "alice"
+            ]
+        );
+    }
+}
@@ -0,0 +1,51 @@
+//! Solves every day with every profile's real input, sealed or not, and checks the answers
+//! against those recorded in `answers.toml`. Without a key to unseal the inputs with, there is
+//! nothing to check, so it passes with a note.
+
+use aoc_core::sealed::{KEY_FILE, KEY_VAR};
+use std::env;
+use std::path::Path;
+use std::process::Command;
+use std::thread;
+
+#[test]
+fn solves_real_inputs() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+    if env::var_os(KEY_VAR).is_none() && !root.join(KEY_FILE).exists() {
+        eprintln!(
+            "skipping: no {} or {} to unseal inputs with",
+            KEY_VAR, KEY_FILE
+        );
+        return;
+    }
+    let year = &aoc_2023::YEAR;
+    let failures = thread::scope(|scope| {
+        let runs = year
+            .days
+            .iter()
+            .map(|day| {
+                let root = &root;
+                scope.spawn(move || {
+                    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+                        .current_dir(root)
+                        .args(["run", &day.number.to_string(), "--all-profiles"])
+                        .args(["--year", &year.year.to_string()])
+                        .output()
+                        .unwrap();
+                    (!output.status.success()).then(|| {
+                        format!(
+                            "day {}:\n{}{}",
+                            day.number,
+                            String::from_utf8_lossy(&output.stdout),
+                            String::from_utf8_lossy(&output.stderr)
+                        )
+                    })
+                })
+            })
+            .collect::<Vec<_>>();
+        runs.into_iter()
+            .filter_map(|run| run.join().unwrap())
+            .collect::<Vec<String>>()
+    });
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
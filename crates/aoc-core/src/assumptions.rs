@@ -0,0 +1,68 @@
+//! Properties of a puzzle's input which a solution relies on without checking as it solves, such
+//! as a shortcut that only works because of how the real inputs were made. Days can declare
+//! theirs, so that `aoc check` can say which hold for a given input.
+
+use std::io::{self, Write};
+
+/// Something a solution assumes about its input, and whether it holds.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Assumption {
+    pub description: &'static str,
+    /// why the assumption doesn't hold, or `None` if it does
+    pub violation: Option<String>,
+}
+
+impl Assumption {
+    pub fn new(description: &'static str, violation: Option<String>) -> Self {
+        Assumption {
+            description,
+            violation,
+        }
+    }
+
+    /// An assumption which holds if `holds`, and otherwise is explained by `violation`.
+    pub fn check(
+        description: &'static str,
+        holds: bool,
+        violation: impl FnOnce() -> String,
+    ) -> Self {
+        Assumption::new(description, (!holds).then(violation))
+    }
+
+    pub fn holds(&self) -> bool {
+        self.violation.is_none()
+    }
+}
+
+/// Writes a line for each assumption, saying whether it holds and if not, why not. Returns
+/// whether they all hold.
+pub fn write_report(assumptions: &[Assumption], out: &mut impl Write) -> io::Result<bool> {
+    for assumption in assumptions {
+        match &assumption.violation {
+            None => writeln!(out, "holds  {}", assumption.description)?,
+            Some(violation) => writeln!(out, "FAILS  {}: {}", assumption.description, violation)?,
+        }
+    }
+    Ok(assumptions.iter().all(Assumption::holds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports() {
+        let assumptions = [
+            Assumption::check("there is a start", true, || unreachable!()),
+            Assumption::check("the maps are sorted", false, || "map 2 isn't".to_owned()),
+        ];
+        assert!(assumptions[0].holds());
+        let mut out = vec![];
+        assert!(!write_report(&assumptions, &mut out).unwrap());
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "holds  there is a start\nFAILS  the maps are sorted: map 2 isn't\n"
+        );
+        assert!(write_report(&assumptions[..1], &mut vec![]).unwrap());
+    }
+}
@@ -0,0 +1,156 @@
+//! Per-day puzzle parameters, such as how much day 11's universe expands, which can be changed
+//! without editing the solutions: first from the day's table in `aoc.toml`, within its year's:
+//!
+//! ```toml
+//! [2023.day11]
+//! expansion = 100
+//! ```
+//!
+//! then from `--param key=value` options, e.g. `--param expansion=10` or `--param bag.red=20`.
+//! Anything not given in either place keeps its default, the value from the puzzle. A parameter
+//! set in `aoc.toml` replaces its default whole, so `bag = { red = 3 }` is a bag with only red
+//! cubes, while `--param` sets a single key, so `--param bag.purple=3` adds purple ones.
+
+use anyhow::{anyhow, bail, Context, Result};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fs;
+use std::io::ErrorKind;
+use toml::{Table, Value};
+
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+/// Loads the parameters for `day` (e.g. `"2023.day11"`) from [`CONFIG_FILE`], if there is one,
+/// then applies `overrides`.
+pub fn load_params<T>(day: &str, overrides: &[String]) -> Result<T>
+where
+    T: Default + Serialize + DeserializeOwned,
+{
+    let config = match fs::read_to_string(CONFIG_FILE) {
+        Ok(config) => config,
+        Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e).context(format!("Could not read {}", CONFIG_FILE)),
+    };
+    params(&config, day, overrides).with_context(|| format!("Bad parameters for {}", day))
+}
+
+/// Starts from the default parameters, replaces those set in the table for `day` from the TOML
+/// text `config`, where `day` is a dotted key such as `"2023.day11"`, then applies `overrides`. Each override is
+/// `key=value`, where the key may be dotted and the value is TOML. A value which isn't valid TOML
+/// is taken to be a string.
+pub fn params<T>(config: &str, day: &str, overrides: &[String]) -> Result<T>
+where
+    T: Default + Serialize + DeserializeOwned,
+{
+    let Value::Table(mut table) = Value::try_from(T::default())? else {
+        bail!("Parameters should be a struct");
+    };
+    let mut config = Value::Table(config.parse()?);
+    for key in day.split('.') {
+        config = match config {
+            Value::Table(mut outer) => outer.remove(key).unwrap_or(Value::Table(Table::new())),
+            _ => bail!("{} in {} should be a table", day, CONFIG_FILE),
+        };
+    }
+    match config {
+        Value::Table(day_table) => table.extend(day_table),
+        _ => bail!("{} in {} should be a table", day, CONFIG_FILE),
+    }
+    for assignment in overrides {
+        let (key, value) = assignment
+            .split_once('=')
+            .ok_or(anyhow!("Expected key=value, found {}", assignment))?;
+        let value = match format!("value = {}", value).parse::<Table>() {
+            Ok(mut parsed) => parsed.remove("value").unwrap(),
+            Err(_) => Value::String(value.to_owned()),
+        };
+        set(&mut table, key.trim(), value)?;
+    }
+    Ok(Value::Table(table).try_into()?)
+}
+
+/// Sets a dotted key in a table, creating any tables along the way.
+fn set(table: &mut Table, key: &str, value: Value) -> Result<()> {
+    match key.split_once('.') {
+        None => {
+            table.insert(key.to_owned(), value);
+        }
+        Some((first, rest)) => {
+            let inner = table
+                .entry(first)
+                .or_insert_with(|| Value::Table(Table::new()));
+            match inner {
+                Value::Table(inner) => set(inner, rest, value)?,
+                _ => bail!("{} isn't a table", first),
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use std::collections::BTreeMap;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    #[serde(default, deny_unknown_fields)]
+    struct Params {
+        expansion: usize,
+        area: (i64, i64),
+        bag: BTreeMap<String, usize>,
+    }
+
+    impl Default for Params {
+        fn default() -> Self {
+            Params {
+                expansion: 2,
+                area: (7, 27),
+                bag: BTreeMap::from([("red".to_owned(), 12)]),
+            }
+        }
+    }
+
+    fn overrides(args: &[&str]) -> Vec<String> {
+        args.iter().map(|&a| a.to_owned()).collect()
+    }
+
+    #[test]
+    fn layers_config_and_overrides() {
+        let config = "[2023.day11]\nexpansion = 10\nbag = { red = 1, blue = 2 }\n\
+                      [2023.day12]\nexpansion = 3\n[2024.day11]\nexpansion = 4";
+        let loaded: Params = params(config, "2023.day11", &[]).unwrap();
+        assert_eq!(loaded.expansion, 10);
+        assert_eq!(loaded.area, (7, 27));
+        assert_eq!(loaded.bag.len(), 2);
+        assert_eq!(loaded.bag["red"], 1);
+
+        let args = overrides(&["expansion=100", "bag.green=5", "area=[1, 2]"]);
+        let loaded: Params = params(config, "2023.day11", &args).unwrap();
+        assert_eq!(loaded.expansion, 100);
+        assert_eq!(loaded.area, (1, 2));
+        assert_eq!(loaded.bag["green"], 5);
+        assert_eq!(loaded.bag["red"], 1);
+
+        let loaded: Params = params(config, "2024.day11", &[]).unwrap();
+        assert_eq!(loaded.expansion, 4);
+        let loaded: Params = params("[2023.day11]\nbag = { blue = 2 }", "2023.day11", &[]).unwrap();
+        assert_eq!(loaded.bag, BTreeMap::from([("blue".to_owned(), 2)]));
+        let loaded: Params = params("", "2023.day11", &[]).unwrap();
+        assert_eq!(loaded, Params::default());
+        let loaded: Params = params("", "2023.day11", &overrides(&["bag.green=5"])).unwrap();
+        assert_eq!((loaded.bag["red"], loaded.bag["green"]), (12, 5));
+    }
+
+    #[test]
+    fn rejects_bad_params() {
+        let bad = |args: &[&str]| params::<Params>("", "2023.day11", &overrides(args)).is_err();
+        assert!(bad(&["expansion"]));
+        assert!(bad(&["expansion=lots"]));
+        assert!(bad(&["expanse=10"]));
+        assert!(bad(&["expansion.x=1"]));
+        assert!(params::<Params>("2023 = 3", "2023.day11", &[]).is_err());
+        assert!(params::<Params>("2023.day11 = 3", "2023.day11", &[]).is_err());
+    }
+}
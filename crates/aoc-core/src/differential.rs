@@ -0,0 +1,254 @@
+//! Differential testing of days with more than one way of solving a part: every implementation
+//! is run on the same generated inputs, and when they disagree the input is shrunk, a chunk of
+//! lines or columns at a time, to the smallest one on which they still disagree.
+
+use crate::random::Rng;
+use crate::runner::{Generate, Solver};
+use std::io::{self, Write};
+use std::panic::{self, AssertUnwindSafe};
+
+/// An input on which the implementations of a part give different answers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mismatch {
+    pub part: u32,
+    pub input: Vec<String>,
+    /// each implementation's answer, or its error
+    pub results: Vec<(&'static str, Result<String, String>)>,
+}
+
+/// Where a mismatch turned up, before it was shrunk.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Found {
+    pub seed: u64,
+    pub size: usize,
+    pub mismatch: Mismatch,
+}
+
+/// The parts which have more than one implementation to compare.
+pub fn parts(solvers: &[Solver]) -> Vec<u32> {
+    let mut parts = solvers.iter().map(|s| s.part).collect::<Vec<u32>>();
+    parts.sort_unstable();
+    parts.dedup();
+    parts.retain(|&part| solvers.iter().filter(|s| s.part == part).count() > 1);
+    parts
+}
+
+/// Runs each part's implementations on an input, returning the first part they disagree on.
+pub fn compare(solvers: &[Solver], input: &[String]) -> Option<Mismatch> {
+    parts(solvers)
+        .into_iter()
+        .find_map(|part| compare_part(solvers, part, input))
+}
+
+/// The implementations disagree if at least one gives an answer and the others don't all give
+/// the same one. An input which every implementation rejects isn't a disagreement, whatever
+/// their errors say, and a panic counts as an error.
+fn compare_part(solvers: &[Solver], part: u32, input: &[String]) -> Option<Mismatch> {
+    let results = solvers
+        .iter()
+        .filter(|s| s.part == part)
+        .map(|s| {
+            let result = panic::catch_unwind(AssertUnwindSafe(|| (s.solve)(input)))
+                .unwrap_or_else(|_| Err(anyhow::anyhow!("panicked")))
+                .map_err(|e| e.to_string());
+            (s.name, result)
+        })
+        .collect::<Vec<_>>();
+    let answers = results
+        .iter()
+        .filter_map(|(_, r)| r.as_ref().ok())
+        .collect::<Vec<_>>();
+    let agree = answers.is_empty()
+        || (answers.len() == results.len() && answers.iter().all(|&a| a == answers[0]));
+    (!agree).then(|| Mismatch {
+        part,
+        input: input.to_vec(),
+        results,
+    })
+}
+
+/// Shrinks the input of a mismatch as far as it can while the same part still disagrees,
+/// removing runs of lines and then, for inputs whose lines are all as long as each other, runs
+/// of columns, halving the runs down to single lines or columns.
+pub fn shrink(solvers: &[Solver], mut mismatch: Mismatch) -> Mismatch {
+    loop {
+        let before = mismatch.input.len() + mismatch.input.first().map_or(0, String::len);
+        mismatch = shrink_by(solvers, mismatch, |input| input.len(), remove_lines);
+        mismatch = shrink_by(solvers, mismatch, columns, remove_columns);
+        if mismatch.input.len() + mismatch.input.first().map_or(0, String::len) == before {
+            return mismatch;
+        }
+    }
+}
+
+fn shrink_by(
+    solvers: &[Solver],
+    mut mismatch: Mismatch,
+    len: fn(&[String]) -> usize,
+    remove: fn(&[String], usize, usize) -> Vec<String>,
+) -> Mismatch {
+    let mut chunk = len(&mismatch.input) / 2;
+    while chunk > 0 {
+        let mut start = 0;
+        while start < len(&mismatch.input) {
+            let candidate = remove(&mismatch.input, start, chunk);
+            match compare_part(solvers, mismatch.part, &candidate) {
+                Some(smaller) => mismatch = smaller,
+                None => start += chunk,
+            }
+        }
+        chunk /= 2;
+    }
+    mismatch
+}
+
+fn remove_lines(input: &[String], start: usize, count: usize) -> Vec<String> {
+    let end = (start + count).min(input.len());
+    [&input[..start], &input[end..]].concat()
+}
+
+/// How many columns there are, or none if the lines aren't all the same length.
+fn columns(input: &[String]) -> usize {
+    match input.first() {
+        Some(first)
+            if input
+                .iter()
+                .all(|line| line.len() == first.len() && line.is_ascii()) =>
+        {
+            first.len()
+        }
+        _ => 0,
+    }
+}
+
+fn remove_columns(input: &[String], start: usize, count: usize) -> Vec<String> {
+    input
+        .iter()
+        .map(|line| {
+            let end = (start + count).min(line.len());
+            format!("{}{}", &line[..start], &line[end..])
+        })
+        .collect()
+}
+
+/// Compares the implementations on `runs` inputs from `generate`, with seeds counting up from
+/// `seed` and sizes growing from 1 to `size`, so that the first mismatch is likely a small one.
+/// Returns the first mismatch found, shrunk.
+pub fn search(
+    solvers: &[Solver],
+    generate: Generate,
+    size: usize,
+    seed: u64,
+    runs: usize,
+) -> Option<Found> {
+    (0..runs).find_map(|run| {
+        let seed = seed.wrapping_add(run as u64);
+        let size = 1 + run * size.saturating_sub(1) / runs.saturating_sub(1).max(1);
+        let input = generate(&mut Rng::new(seed), size);
+        compare(solvers, &input).map(|mismatch| Found {
+            seed,
+            size,
+            mismatch: shrink(solvers, mismatch),
+        })
+    })
+}
+
+pub fn write_mismatch(mismatch: &Mismatch, out: &mut impl Write) -> io::Result<()> {
+    writeln!(out, "part {} disagrees on:", mismatch.part)?;
+    for line in &mismatch.input {
+        writeln!(out, "  {}", line)?;
+    }
+    for (name, result) in &mismatch.results {
+        match result {
+            Ok(answer) => writeln!(out, "{} = {}", name, answer)?,
+            Err(error) => writeln!(out, "{} failed: {}", name, error)?,
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    fn sum(input: &[String]) -> Result<String> {
+        let numbers = input
+            .iter()
+            .map(|line| line.parse::<u32>())
+            .collect::<Result<Vec<u32>, _>>()?;
+        Ok(numbers.iter().sum::<u32>().to_string())
+    }
+
+    /// Forgets about sevens.
+    fn buggy_sum(input: &[String]) -> Result<String> {
+        let lines = input.iter().filter(|&line| line != "7").cloned();
+        sum(&lines.collect::<Vec<String>>())
+    }
+
+    fn count_rocks(input: &[String]) -> Result<String> {
+        Ok(input.concat().matches('#').count().to_string())
+    }
+
+    /// Counts two rocks side by side as one.
+    fn buggy_count_rocks(input: &[String]) -> Result<String> {
+        let lines = input.iter().map(|line| line.replace("##", "#"));
+        count_rocks(&lines.collect::<Vec<String>>())
+    }
+
+    fn lines(input: &[&str]) -> Vec<String> {
+        input.iter().map(|&s| s.to_owned()).collect()
+    }
+
+    const SOLVERS: &[Solver] = &[
+        Solver::new(1, "sum", sum),
+        Solver::new(1, "buggy-sum", buggy_sum),
+        Solver::new(2, "rocks", count_rocks),
+        Solver::new(2, "buggy-rocks", buggy_count_rocks),
+    ];
+
+    #[test]
+    fn compares() {
+        assert_eq!(parts(SOLVERS), [1, 2]);
+        assert_eq!(parts(&SOLVERS[..3]), [1]);
+        assert_eq!(compare(SOLVERS, &lines(&["1", "2"])), None);
+        // neither sum can read it, which is agreement of a kind
+        assert_eq!(compare(&SOLVERS[..2], &lines(&["x"])), None);
+        let mismatch = compare(SOLVERS, &lines(&["1", "7"])).unwrap();
+        assert_eq!(mismatch.part, 1);
+        assert_eq!(
+            mismatch.results,
+            [
+                ("sum", Ok("8".to_owned())),
+                ("buggy-sum", Ok("1".to_owned()))
+            ]
+        );
+        let panics = Solver::new(1, "panics", |_| panic!("oops"));
+        let mismatch = compare(&[SOLVERS[0], panics], &lines(&["1"])).unwrap();
+        assert_eq!(mismatch.results[1], ("panics", Err("panicked".to_owned())));
+    }
+
+    #[test]
+    fn shrinks() {
+        let mismatch = compare(SOLVERS, &lines(&["3", "9", "4", "7", "1", "7", "2"])).unwrap();
+        assert_eq!(shrink(SOLVERS, mismatch).input, ["7"]);
+
+        let grid = lines(&["..##.", ".....", "#..#."]);
+        let mismatch = compare(&SOLVERS[2..], &grid).unwrap();
+        let mut out = vec![];
+        write_mismatch(&shrink(SOLVERS, mismatch), &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "part 2 disagrees on:\n  ##\nrocks = 2\nbuggy-rocks = 1\n"
+        );
+    }
+
+    #[test]
+    fn searches() {
+        let generate: Generate = |rng, size| (0..size).map(|_| rng.below(8).to_string()).collect();
+        let found = search(&SOLVERS[..2], generate, 20, 0, 50).unwrap();
+        assert!(found.size <= 20);
+        assert_eq!(found.mismatch.input, ["7"]);
+        assert_eq!(search(&SOLVERS[..1], generate, 20, 0, 50), None);
+    }
+}
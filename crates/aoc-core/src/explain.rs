@@ -0,0 +1,179 @@
+//! Narratives of how a solution reaches its answers, asked for with `--explain`. A narrative is a
+//! series of titled tables, which can be written as aligned text or as JSON.
+
+use anyhow::{bail, Error, Result};
+use serde_json::{json, Map};
+use std::io::{self, Write};
+use std::str::FromStr;
+
+pub use serde_json::Value;
+
+/// How to write an explanation.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => bail!("Unknown format {}, expected text or json", s),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Explanation {
+    pub sections: Vec<Section>,
+}
+
+/// A table of one step of a solution, such as one seed's path through the maps.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Section {
+    pub title: String,
+    pub columns: Vec<&'static str>,
+    pub rows: Vec<Vec<Value>>,
+}
+
+impl Explanation {
+    /// Starts a new section, to be filled in with [`Section::row`].
+    pub fn section(&mut self, title: impl Into<String>, columns: &[&'static str]) -> &mut Section {
+        self.sections.push(Section {
+            title: title.into(),
+            columns: columns.to_vec(),
+            rows: vec![],
+        });
+        self.sections.last_mut().unwrap()
+    }
+
+    pub fn write(&self, format: Format, out: &mut impl Write) -> io::Result<()> {
+        match format {
+            Format::Text => self.write_text(out),
+            Format::Json => writeln!(out, "{:#}", self.to_json()),
+        }
+    }
+
+    /// Writes each section as its title followed by a table, with a blank line between them.
+    fn write_text(&self, out: &mut impl Write) -> io::Result<()> {
+        for (i, section) in self.sections.iter().enumerate() {
+            if i > 0 {
+                writeln!(out)?;
+            }
+            writeln!(out, "{}", section.title)?;
+            let rows = section
+                .rows
+                .iter()
+                .map(|row| row.iter().map(text).collect::<Vec<String>>())
+                .collect::<Vec<Vec<String>>>();
+            let mut widths = section
+                .columns
+                .iter()
+                .map(|c| c.len())
+                .collect::<Vec<usize>>();
+            for row in &rows {
+                for (width, cell) in widths.iter_mut().zip(row) {
+                    *width = (*width).max(cell.len());
+                }
+            }
+            let header = section.columns.iter().map(|&c| c.to_owned()).collect();
+            for row in [header].iter().chain(&rows) {
+                let cells = row
+                    .iter()
+                    .zip(&widths)
+                    .map(|(cell, &width)| format!("{:width$}", cell, width = width))
+                    .collect::<Vec<String>>();
+                writeln!(out, "  {}", cells.join("  ").trim_end())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The sections as a JSON array, each row an object keyed by column.
+    pub fn to_json(&self) -> Value {
+        self.sections
+            .iter()
+            .map(|section| {
+                let rows = section
+                    .rows
+                    .iter()
+                    .map(|row| {
+                        let cells = section.columns.iter().map(|&c| c.to_owned());
+                        Value::Object(cells.zip(row.iter().cloned()).collect::<Map<_, _>>())
+                    })
+                    .collect::<Vec<Value>>();
+                json!({ "title": section.title, "rows": rows })
+            })
+            .collect()
+    }
+}
+
+impl Section {
+    pub fn row(&mut self, cells: impl IntoIterator<Item = Value>) -> &mut Self {
+        self.rows.push(cells.into_iter().collect());
+        self
+    }
+}
+
+/// A cell as text: strings without quotes, arrays as their elements separated by spaces, and
+/// nothing for null.
+fn text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        Value::Array(values) => values.iter().map(text).collect::<Vec<String>>().join(" "),
+        value => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn explanation() -> Explanation {
+        let mut explanation = Explanation::default();
+        explanation
+            .section("seed 79", &["map", "from", "to"])
+            .row(["seed-to-soil".into(), 79.into(), 81.into()])
+            .row(["soil-to-fertilizer".into(), 81.into(), 81.into()]);
+        explanation
+            .section("history 1", &["depth", "values"])
+            .row([0.into(), vec![0, 3, 6].into()])
+            .row([1.into(), Value::Null]);
+        explanation
+    }
+
+    #[test]
+    fn writes_text() {
+        let mut out = vec![];
+        explanation().write(Format::Text, &mut out).unwrap();
+        let expected = "\
+seed 79
+  map                 from  to
+  seed-to-soil        79    81
+  soil-to-fertilizer  81    81
+
+history 1
+  depth  values
+  0      0 3 6
+  1
+";
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+    }
+
+    #[test]
+    fn writes_json() {
+        let json = explanation().to_json();
+        assert_eq!(json[0]["title"], "seed 79");
+        assert_eq!(json[0]["rows"][1]["map"], "soil-to-fertilizer");
+        assert_eq!(json[0]["rows"][1]["from"], 81);
+        assert_eq!(json[1]["rows"][0]["values"], json!([0, 3, 6]));
+        assert_eq!("json".parse::<Format>().unwrap(), Format::Json);
+        assert!("yaml".parse::<Format>().is_err());
+    }
+}
@@ -0,0 +1,172 @@
+//! Shapes on the integer grid, and vectors in three dimensions.
+
+use nalgebra::{Scalar, Vector3};
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Mul, Sub};
+
+/// Twice the area of the polygon with the given vertices in order, by the shoelace formula. The
+/// polygon is closed automatically, so the last vertex may or may not repeat the first. Doubling
+/// keeps the result exact, as the area of a lattice polygon is a multiple of a half.
+pub fn polygon_area2(vertices: &[(isize, isize)]) -> isize {
+    let next = vertices.iter().cycle().skip(1);
+    vertices
+        .iter()
+        .zip(next)
+        .map(|(&(x1, y1), &(x2, y2))| x1 * y2 - x2 * y1)
+        .sum::<isize>()
+        .abs()
+}
+
+/// How many lattice points lie strictly inside a polygon whose vertices are lattice points, given
+/// twice its area and the number of lattice points on its boundary (Pick's theorem).
+pub fn interior_points(area2: isize, boundary_points: isize) -> isize {
+    (area2 - boundary_points + 2) / 2
+}
+
+/// A vector in three dimensions, generic so that it can hold exact values such as `BigInt` as well
+/// as machine integers.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Vec3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Vec3<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Vec3 { x, y, z }
+    }
+
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> Vec3<U> {
+        Vec3::new(f(self.x), f(self.y), f(self.z))
+    }
+}
+
+impl<T: Clone + Mul<Output = T>> Vec3<T> {
+    pub fn scale(&self, k: &T) -> Self {
+        self.clone().map(|c| c * k.clone())
+    }
+}
+
+impl<T: Clone + Add<Output = T> + Mul<Output = T>> Vec3<T> {
+    pub fn dot(&self, other: &Self) -> T {
+        let (a, b) = (self.clone(), other.clone());
+        a.x * b.x + a.y * b.y + a.z * b.z
+    }
+}
+
+impl<T: Clone + Sub<Output = T> + Mul<Output = T>> Vec3<T> {
+    pub fn cross(&self, other: &Self) -> Self {
+        let (a, b) = (self.clone(), other.clone());
+        Vec3::new(
+            a.y.clone() * b.z.clone() - a.z.clone() * b.y.clone(),
+            a.z * b.x.clone() - a.x.clone() * b.z,
+            a.x * b.y - a.y * b.x,
+        )
+    }
+}
+
+impl<T: Clone + Into<BigInt>> Vec3<T> {
+    pub fn to_bigint(&self) -> Vec3<BigInt> {
+        self.clone().map(Into::into)
+    }
+}
+
+impl<T: ToPrimitive> Vec3<T> {
+    /// Converts to floating point, which may lose precision, or `None` if a value is out of range.
+    pub fn to_f64(&self) -> Option<Vec3<f64>> {
+        Some(Vec3::new(
+            self.x.to_f64()?,
+            self.y.to_f64()?,
+            self.z.to_f64()?,
+        ))
+    }
+}
+
+impl<T: Add<Output = T>> Add for Vec3<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Vec3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Vec3<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Vec3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T: Display> Display for Vec3<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+impl<T> From<[T; 3]> for Vec3<T> {
+    fn from([x, y, z]: [T; 3]) -> Self {
+        Vec3::new(x, y, z)
+    }
+}
+
+impl<T> From<Vec3<T>> for [T; 3] {
+    fn from(v: Vec3<T>) -> Self {
+        [v.x, v.y, v.z]
+    }
+}
+
+impl<T: Scalar> From<Vec3<T>> for Vector3<T> {
+    fn from(v: Vec3<T>) -> Self {
+        Vector3::new(v.x, v.y, v.z)
+    }
+}
+
+impl<T: Scalar> From<Vector3<T>> for Vec3<T> {
+    fn from(v: Vector3<T>) -> Self {
+        let [[x, y, z]] = v.data.0;
+        Vec3::new(x, y, z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn areas() {
+        let square = [(0, 0), (4, 0), (4, 4), (0, 4)];
+        assert_eq!(polygon_area2(&square), 32);
+        let closed = [(0, 0), (0, 4), (4, 4), (4, 0), (0, 0)];
+        assert_eq!(polygon_area2(&closed), 32);
+        assert_eq!(interior_points(32, 16), 9);
+
+        let triangle = [(0, 0), (3, 0), (0, 3)];
+        assert_eq!(polygon_area2(&triangle), 9);
+        assert_eq!(interior_points(9, 9), 1);
+    }
+
+    #[test]
+    fn vector_algebra() {
+        let a = Vec3::new(1, 2, 3);
+        let b = Vec3::from([4, -5, 6]);
+        assert_eq!(a + b, Vec3::new(5, -3, 9));
+        assert_eq!(a - b, Vec3::new(-3, 7, -3));
+        assert_eq!(a.scale(&2), Vec3::new(2, 4, 6));
+        assert_eq!(a.dot(&b), 12);
+        assert_eq!(a.cross(&b), Vec3::new(27, 6, -13));
+        assert_eq!(a.cross(&b).dot(&a), 0);
+
+        let big = Vec3::new(i64::MAX, 1, 0).to_bigint();
+        assert_eq!(big.scale(&BigInt::from(2)).x, BigInt::from(i64::MAX) * 2);
+        assert_eq!(a.to_f64(), Some(Vec3::new(1.0, 2.0, 3.0)));
+        let v: Vector3<i32> = a.into();
+        assert_eq!(v, Vector3::new(1, 2, 3));
+        assert_eq!(Vec3::from(v), a);
+        assert_eq!(<[i32; 3]>::from(a), [1, 2, 3]);
+        assert_eq!(b.to_string(), "(4, -5, 6)");
+    }
+}
@@ -0,0 +1,158 @@
+//! Positions and directions on a rectangular grid of tiles, such as a puzzle input where each
+//! line is a row.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// An (x, y) position, with y increasing downwards.
+pub type Pos = (usize, usize);
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+
+    /// The change in (x, y) from moving one tile this way.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+        }
+    }
+}
+
+/// The size of a grid.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Bounds {
+    pub width: usize,
+    pub height: usize,
+}
+
+/// A walk tried to leave the grid.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct OffGrid {
+    pub from: Pos,
+    pub direction: Direction,
+}
+
+impl Display for OffGrid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Leaving {:?} going {:?} leads off the grid",
+            self.from, self.direction
+        )
+    }
+}
+
+impl Error for OffGrid {}
+
+impl Bounds {
+    /// The bounds of a grid with a row per line, as wide as its first line.
+    pub fn of<S: AsRef<str>>(rows: &[S]) -> Bounds {
+        Bounds {
+            width: rows.first().map_or(0, |row| row.as_ref().len()),
+            height: rows.len(),
+        }
+    }
+
+    /// Whether a position is on the grid.
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// The position one tile away in direction `d`, if that is still on the grid.
+    pub fn step(&self, (x, y): Pos, d: Direction) -> Option<Pos> {
+        let (dx, dy) = d.offset();
+        let x = x.checked_add_signed(dx).filter(|&x| x < self.width)?;
+        let y = y.checked_add_signed(dy).filter(|&y| y < self.height)?;
+        Some((x, y))
+    }
+
+    /// Index of a position in a `Vec` holding a value for each tile, row by row.
+    pub fn index(&self, (x, y): Pos) -> usize {
+        y * self.width + x
+    }
+
+    /// Walks from `start` in direction `d`, one tile at a time. `turn` is called with each tile
+    /// reached and the direction it was entered in, and returns which way to leave it, or `None`
+    /// to stop there. Returns every tile visited in order, starting with `start`.
+    ///
+    /// This loops rather than recursing. Recursing would take a stack frame per tile, and a
+    /// thread's stack only has room for so many, so a long enough walk would overflow it; a
+    /// loop lets a walk be as long as memory allows.
+    pub fn walk<E: From<OffGrid>>(
+        &self,
+        start: Pos,
+        mut d: Direction,
+        mut turn: impl FnMut(Pos, Direction) -> Result<Option<Direction>, E>,
+    ) -> Result<Vec<Pos>, E> {
+        let mut path = vec![start];
+        let mut pos = start;
+        loop {
+            pos = self.step(pos, d).ok_or(OffGrid {
+                from: pos,
+                direction: d,
+            })?;
+            path.push(pos);
+            match turn(pos, d)? {
+                Some(next) => d = next,
+                None => return Ok(path),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn walks_around_the_edge() {
+        let bounds = Bounds::of(&["....", "....", "...."]);
+        let error = bounds
+            .walk((0, 1), Direction::Right, |_, d| Ok::<_, OffGrid>(Some(d)))
+            .unwrap_err();
+        assert_eq!(error.from, (3, 1));
+
+        let path = bounds
+            .walk((0, 0), Direction::Right, |pos, d| {
+                Ok::<_, OffGrid>(match pos {
+                    (0, 0) => None,
+                    _ if bounds.step(pos, d).is_some() => Some(d),
+                    _ => Some(match d {
+                        Direction::Right => Direction::Down,
+                        Direction::Down => Direction::Left,
+                        Direction::Left => Direction::Up,
+                        Direction::Up => Direction::Right,
+                    }),
+                })
+            })
+            .unwrap();
+        assert_eq!(path.len(), 11);
+        assert_eq!(path[5], (3, 2));
+        assert_eq!(bounds.index((3, 2)), 11);
+    }
+}
@@ -0,0 +1,128 @@
+//! Line-at-a-time input, so that solutions which only need one line at a time can run over
+//! inputs too big to hold in memory.
+
+use crate::parse::Line;
+use crate::sealed::{read_sealed, sealed_path, Key};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Cursor, ErrorKind, Lines, Read};
+use std::ops::Add;
+use std::path::Path;
+
+/// Anything yielding lines of input one at a time, such as a file being streamed by
+/// [`stream_lines`] or lines already in memory via [`memory_lines`].
+pub trait LineSource: IntoIterator<Item = io::Result<Self::Text>> {
+    type Text: AsRef<str>;
+}
+
+impl<I, S> LineSource for I
+where
+    I: IntoIterator<Item = io::Result<S>>,
+    S: AsRef<str>,
+{
+    type Text = S;
+}
+
+/// Opens a puzzle input. If there is no such file but there is a sealed copy of it (see
+/// [`crate::sealed`]), that is decrypted in memory instead.
+pub fn open(path: impl AsRef<Path>) -> io::Result<Box<dyn Read + Send>> {
+    open_with_key(path.as_ref(), Key::load)
+}
+
+/// Like [`open`], but with the key to unseal inputs with coming from `load_key`, which is only
+/// called if the input is sealed.
+fn open_with_key(
+    path: &Path,
+    load_key: impl FnOnce() -> anyhow::Result<Key>,
+) -> io::Result<Box<dyn Read + Send>> {
+    match File::open(path) {
+        Ok(file) => Ok(Box::new(file)),
+        Err(e) if e.kind() == ErrorKind::NotFound && sealed_path(path).is_file() => {
+            let plaintext = load_key()
+                .and_then(|key| read_sealed(&sealed_path(path), &key))
+                .map_err(io::Error::other)?;
+            Ok(Box::new(Cursor::new(plaintext)))
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Opens a puzzle input to be read one line at a time.
+pub fn stream_lines(path: impl AsRef<Path>) -> io::Result<Lines<BufReader<Box<dyn Read + Send>>>> {
+    Ok(BufReader::new(open(path)?).lines())
+}
+
+pub fn memory_lines<S: AsRef<str> + Sync>(
+    input: &[S],
+) -> impl Iterator<Item = io::Result<&str>> + Send {
+    input.iter().map(|line| Ok(line.as_ref()))
+}
+
+/// Folds over the lines of `input`, holding only one line in memory at a time.
+pub fn fold_lines<B, E: From<io::Error>>(
+    input: impl LineSource,
+    init: B,
+    mut f: impl FnMut(B, Line<'_>) -> Result<B, E>,
+) -> Result<B, E> {
+    let mut acc = init;
+    for (index, text) in input.into_iter().enumerate() {
+        let text = text?;
+        acc = f(acc, Line::new(index, text.as_ref()))?;
+    }
+    Ok(acc)
+}
+
+/// Sums a value calculated independently for each line of `input`.
+pub fn sum_lines<T: Default + Add<Output = T>, E: From<io::Error>>(
+    input: impl LineSource,
+    mut f: impl FnMut(Line<'_>) -> Result<T, E>,
+) -> Result<T, E> {
+    fold_lines(input, T::default(), |acc, line| Ok(acc + f(line)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers_lines() {
+        let input = ["a", "bb", "ccc"];
+        let answer = fold_lines(memory_lines(&input), vec![], |mut acc, line| {
+            acc.push((line.index, line.text.len()));
+            Ok::<_, io::Error>(acc)
+        })
+        .unwrap();
+        assert_eq!(answer, vec![(0, 1), (1, 2), (2, 3)]);
+    }
+
+    #[test]
+    fn stops_at_first_error() {
+        let input = vec![
+            Ok("1".to_owned()),
+            Err(io::Error::other("disk on fire")),
+            Ok("2".to_owned()),
+        ];
+        let error = sum_lines(input, |line| Ok::<usize, io::Error>(line.text.len())).unwrap_err();
+        assert_eq!(error.to_string(), "disk on fire");
+    }
+
+    #[test]
+    fn reads_sealed_input() {
+        let root = std::env::temp_dir().join(format!("aoc-input-{}", std::process::id()));
+        let dir = root.join("data/2023");
+        std::fs::create_dir_all(&dir).unwrap();
+        let (key, _) = Key::generate();
+        let sealed = key.seal("2023/day09.txt", b"0 3 6\n1 3 6 10\n");
+        std::fs::write(dir.join("day09.txt.enc"), sealed).unwrap();
+
+        let input = open_with_key(&dir.join("day09.txt"), || Ok(key.clone())).unwrap();
+        let lines = BufReader::new(input)
+            .lines()
+            .collect::<io::Result<Vec<String>>>()
+            .unwrap();
+        assert_eq!(lines, ["0 3 6", "1 3 6 10"]);
+        let (other_key, _) = Key::generate();
+        assert!(open_with_key(&dir.join("day09.txt"), || Ok(other_key)).is_err());
+        assert!(open_with_key(&dir.join("day10.txt"), || Ok(key.clone())).is_err());
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
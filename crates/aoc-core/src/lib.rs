@@ -0,0 +1,17 @@
+pub mod assumptions;
+pub mod config;
+pub mod differential;
+pub mod explain;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod linear;
+pub mod number;
+pub mod parallel;
+pub mod parse;
+pub mod profiles;
+pub mod random;
+pub mod runner;
+pub mod scan;
+pub mod sealed;
+pub mod search;
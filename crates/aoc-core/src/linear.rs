@@ -0,0 +1,71 @@
+//! Exact solutions of systems of linear equations.
+
+use num_rational::BigRational;
+use num_traits::Zero;
+
+/// Solves `matrix * x = rhs` for `x` by Gaussian elimination, without any rounding. Returns
+/// `None` if the matrix is singular, so that there isn't a unique solution.
+pub fn solve(
+    mut matrix: Vec<Vec<BigRational>>,
+    mut rhs: Vec<BigRational>,
+) -> Option<Vec<BigRational>> {
+    let n = rhs.len();
+    assert!(
+        matrix.len() == n && matrix.iter().all(|row| row.len() == n),
+        "Expected a square matrix with a row per equation"
+    );
+    for col in 0..n {
+        let pivot = (col..n).find(|&row| !matrix[row][col].is_zero())?;
+        matrix.swap(col, pivot);
+        rhs.swap(col, pivot);
+        let pivot_row = matrix[col].clone();
+        let pivot_rhs = rhs[col].clone();
+        for row in (0..n).filter(|&row| row != col) {
+            if !matrix[row][col].is_zero() {
+                let factor = &matrix[row][col] / &pivot_row[col];
+                for (x, p) in matrix[row][col..].iter_mut().zip(&pivot_row[col..]) {
+                    *x -= &factor * p;
+                }
+                rhs[row] -= &factor * &pivot_rhs;
+            }
+        }
+    }
+    Some(
+        rhs.into_iter()
+            .zip(matrix)
+            .enumerate()
+            .map(|(i, (r, row))| r / &row[i])
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_bigint::BigInt;
+
+    fn rationals(ns: &[i64]) -> Vec<BigRational> {
+        ns.iter()
+            .map(|&n| BigRational::from(BigInt::from(n)))
+            .collect()
+    }
+
+    #[test]
+    fn solves_exactly() {
+        // 2x + y = 1, x + 3y = 2 => x = 1/5, y = 3/5
+        let matrix = vec![rationals(&[2, 1]), rationals(&[1, 3])];
+        let x = solve(matrix, rationals(&[1, 2])).unwrap();
+        let fifth = |n| BigRational::new(BigInt::from(n), BigInt::from(5));
+        assert_eq!(x, vec![fifth(1), fifth(3)]);
+
+        // needs a row swap to find a pivot
+        let matrix = vec![rationals(&[0, 1]), rationals(&[1, 0])];
+        assert_eq!(
+            solve(matrix, rationals(&[7, 9])).unwrap(),
+            rationals(&[9, 7])
+        );
+
+        let singular = vec![rationals(&[1, 2]), rationals(&[2, 4])];
+        assert_eq!(solve(singular, rationals(&[1, 2])), None);
+    }
+}
@@ -0,0 +1,26 @@
+//! Number theory.
+
+use num_integer::Integer;
+
+/// The least common multiple of some numbers, which is 1 if there are none.
+pub fn lcm_all<T: Integer + Copy>(ns: impl IntoIterator<Item = T>) -> T {
+    ns.into_iter().fold(T::one(), |acc, n| acc.lcm(&n))
+}
+
+/// The greatest common divisor of some numbers, which is 0 if there are none.
+pub fn gcd_all<T: Integer + Copy>(ns: impl IntoIterator<Item = T>) -> T {
+    ns.into_iter().fold(T::zero(), |acc, n| acc.gcd(&n))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn multiples_and_divisors() {
+        assert_eq!(lcm_all([4usize, 6, 10]), 60);
+        assert_eq!(lcm_all::<u64>([]), 1);
+        assert_eq!(gcd_all([12i64, -18, 30]), 6);
+        assert_eq!(gcd_all::<u32>([]), 0);
+    }
+}
@@ -0,0 +1,130 @@
+//! Helpers which spread independent pieces of work across threads when the `parallel` feature is
+//! enabled, and otherwise run them in order on the current thread. Either way the results are
+//! combined with `+`, so both give the same answer.
+
+use crate::input::LineSource;
+use crate::parse::Line;
+use anyhow::{bail, Result};
+use std::io;
+use std::iter::Sum;
+use std::ops::Add;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Sets the number of threads used by the helpers in this module. Without the `parallel`
+/// feature only a single thread is available.
+pub fn configure_threads(threads: usize) -> Result<()> {
+    if threads == 0 {
+        bail!("Thread count must be at least 1");
+    }
+    #[cfg(feature = "parallel")]
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build_global()?;
+    #[cfg(not(feature = "parallel"))]
+    if threads > 1 {
+        bail!("Built without the parallel feature, so only 1 thread is available");
+    }
+    Ok(())
+}
+
+/// Runs `f` with the helpers in this module using `threads` threads, rather than however many
+/// [`configure_threads`] set, so that answers can be compared across thread counts.
+#[cfg(feature = "parallel")]
+pub fn with_threads<R: Send>(threads: usize, f: impl FnOnce() -> R + Send) -> Result<R> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()?;
+    Ok(pool.install(f))
+}
+
+/// Sums `f` applied to each item of `items` along with its index.
+#[cfg(feature = "parallel")]
+pub fn sum_indexed<T: Sync, S: Send + Sum>(
+    items: &[T],
+    f: impl Fn(usize, &T) -> S + Sync + Send,
+) -> S {
+    items.par_iter().enumerate().map(|(i, t)| f(i, t)).sum()
+}
+
+/// Sums `f` applied to each item of `items` along with its index.
+#[cfg(not(feature = "parallel"))]
+pub fn sum_indexed<T: Sync, S: Send + Sum>(
+    items: &[T],
+    f: impl Fn(usize, &T) -> S + Sync + Send,
+) -> S {
+    items.iter().enumerate().map(|(i, t)| f(i, t)).sum()
+}
+
+/// Like [`crate::input::sum_lines`], but lines may be handed out to several threads. If more
+/// than one line fails, which of the errors is returned is not defined.
+#[cfg(feature = "parallel")]
+pub fn sum_lines<L, T, E>(
+    input: L,
+    f: impl Fn(Line<'_>) -> Result<T, E> + Sync + Send,
+) -> Result<T, E>
+where
+    L: LineSource,
+    L::IntoIter: Send,
+    L::Text: Send,
+    T: Default + Add<Output = T> + Send,
+    E: From<io::Error> + Send,
+{
+    input
+        .into_iter()
+        .enumerate()
+        .par_bridge()
+        .map(|(index, text)| f(Line::new(index, text?.as_ref())))
+        .try_reduce(T::default, |a, b| Ok(a + b))
+}
+
+/// Like [`crate::input::sum_lines`], but lines may be handed out to several threads. If more
+/// than one line fails, which of the errors is returned is not defined.
+#[cfg(not(feature = "parallel"))]
+pub fn sum_lines<L, T, E>(
+    input: L,
+    f: impl Fn(Line<'_>) -> Result<T, E> + Sync + Send,
+) -> Result<T, E>
+where
+    L: LineSource,
+    L::IntoIter: Send,
+    L::Text: Send,
+    T: Default + Add<Output = T> + Send,
+    E: From<io::Error> + Send,
+{
+    crate::input::sum_lines(input, f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::memory_lines;
+
+    #[test]
+    fn sums_pairs() {
+        let items = (1..=100).collect::<Vec<usize>>();
+        let answer: usize = sum_indexed(&items, |i, &x| items[i + 1..].iter().map(|y| x * y).sum());
+        let expected: usize = (1..=100)
+            .flat_map(|x| (x + 1..=100).map(move |y| x * y))
+            .sum();
+        assert_eq!(answer, expected);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn runs_with_threads() {
+        let threads = with_threads(3, rayon::current_num_threads).unwrap();
+        assert_eq!(threads, 3);
+    }
+
+    #[test]
+    fn sums_lines() {
+        let input = (0..1000).map(|i| i.to_string()).collect::<Vec<String>>();
+        let answer = sum_lines(memory_lines(&input), |line| {
+            Ok::<usize, anyhow::Error>(line.parse::<usize>(line.text, "a number")? * line.index)
+        })
+        .unwrap();
+        assert_eq!(answer, (0..1000).map(|i| i * i).sum::<usize>());
+    }
+}
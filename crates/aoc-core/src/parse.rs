@@ -0,0 +1,181 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// A failure to parse puzzle input, located at a line and column of the input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number
+    pub line: usize,
+    /// 1-based column, counted in chars
+    pub column: usize,
+    /// the offending text
+    pub text: String,
+    /// description of what should have been there instead
+    pub expected: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {:?}",
+            self.line, self.column, self.expected, self.text
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// One line of puzzle input, remembering where it came from so errors can be located.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Line<'a> {
+    /// 0-based index of the line within the input
+    pub index: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn new(index: usize, text: &'a str) -> Self {
+        Line { index, text }
+    }
+
+    /// Fetches line `index` of `input`, or an error at the end of the input if there are too few
+    /// lines.
+    pub fn get(input: &'a [String], index: usize, expected: &str) -> Result<Self, ParseError> {
+        input
+            .get(index)
+            .map(|text| Line::new(index, text))
+            .ok_or_else(|| end_of_input(input, expected))
+    }
+
+    /// Builds an error for `fragment`, which should be a slice of this line's text.
+    pub fn error(&self, fragment: &str, expected: &str) -> ParseError {
+        ParseError {
+            line: self.index + 1,
+            column: self.column_of(fragment),
+            text: fragment.to_owned(),
+            expected: expected.to_owned(),
+        }
+    }
+
+    /// 1-based column at which `fragment` starts, or 1 if it is not part of this line.
+    pub fn column_of(&self, fragment: &str) -> usize {
+        (fragment.as_ptr() as usize)
+            .checked_sub(self.text.as_ptr() as usize)
+            .filter(|&offset| offset <= self.text.len())
+            .and_then(|offset| self.text.get(..offset))
+            .map(|prefix| prefix.chars().count() + 1)
+            .unwrap_or(1)
+    }
+
+    pub fn parse<T: FromStr>(&self, fragment: &str, expected: &str) -> Result<T, ParseError> {
+        fragment
+            .parse::<T>()
+            .map_err(|_| self.error(fragment, expected))
+    }
+
+    pub fn split_once(
+        &self,
+        fragment: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        fragment
+            .split_once(delimiter)
+            .ok_or_else(|| self.error(fragment, &format!("{:?}", delimiter)))
+    }
+
+    pub fn strip_prefix(&self, fragment: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        fragment
+            .strip_prefix(prefix)
+            .ok_or_else(|| self.error(fragment, &format!("{:?}", prefix)))
+    }
+
+    /// Parses a whitespace separated list of numbers.
+    pub fn numbers<T: FromStr>(&self, fragment: &str) -> Result<Vec<T>, ParseError> {
+        fragment
+            .split_whitespace()
+            .map(|n| self.parse(n, "a number"))
+            .collect()
+    }
+}
+
+pub fn lines(input: &[String]) -> impl Iterator<Item = Line<'_>> {
+    input
+        .iter()
+        .enumerate()
+        .map(|(index, text)| Line::new(index, text))
+}
+
+/// Splits the input into non-empty groups of lines separated by blank lines.
+pub fn sections(input: &[String]) -> Vec<Vec<Line<'_>>> {
+    lines(input)
+        .collect::<Vec<Line>>()
+        .split(|line| line.text.trim().is_empty())
+        .filter(|section| !section.is_empty())
+        .map(|section| section.to_vec())
+        .collect()
+}
+
+/// An error for input that ended before something `expected` was found.
+pub fn end_of_input(input: &[String], expected: &str) -> ParseError {
+    ParseError {
+        line: input.len() + 1,
+        column: 1,
+        text: String::new(),
+        expected: expected.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_fragment() {
+        let line = Line::new(2, "Game 12: 3 blue");
+        let error = line
+            .parse::<usize>(&line.text[9..15], "a number")
+            .unwrap_err();
+        assert_eq!(
+            error,
+            ParseError {
+                line: 3,
+                column: 10,
+                text: "3 blue".to_owned(),
+                expected: "a number".to_owned(),
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 10: expected a number, found \"3 blue\""
+        );
+    }
+
+    #[test]
+    fn missing_line() {
+        let input = vec!["seeds: 1 2".to_owned()];
+        let error = Line::get(&input, 2, "a map").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    #[test]
+    fn sections_skip_blank_lines() {
+        let input = ["a", "", "b", "c", " ", "", "d", ""].map(|s| s.to_owned());
+        let sections = sections(&input);
+        let indexes: Vec<Vec<usize>> = sections
+            .iter()
+            .map(|section| section.iter().map(|line| line.index).collect())
+            .collect();
+        assert_eq!(indexes, vec![vec![0], vec![2, 3], vec![6]]);
+    }
+
+    #[test]
+    fn numbers() {
+        let line = Line::new(0, " 41 48  6 | x");
+        assert_eq!(
+            line.numbers::<usize>(&line.text[..9]).unwrap(),
+            vec![41, 48, 6]
+        );
+        assert_eq!(line.numbers::<usize>(line.text).unwrap_err().column, 11);
+    }
+}
@@ -0,0 +1,278 @@
+//! Puzzle inputs from several people, called profiles, and the answers recorded for each. The
+//! default profile's inputs are `data/<year>/dayNN.txt`; any other profile's are in a directory
+//! alongside them, `data/<year>/<profile>/dayNN.txt`. Answers are recorded in
+//! `data/<year>/answers.toml`, by profile and then day:
+//!
+//! ```toml
+//! [alice]
+//! day08 = ["15517", "14935034899483"]
+//! ```
+
+use crate::sealed::sealed_path;
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+/// The profile whose inputs are in the year's data directory itself, rather than a subdirectory.
+pub const DEFAULT_PROFILE: &str = "default";
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// The default profile's input for a day.
+pub fn input_path(year: u32, day: u32) -> PathBuf {
+    PathBuf::from(format!("./data/{}/day{:02}.txt", year, day))
+}
+
+/// The profiles with an input for a day, given the default profile's input: the default first,
+/// if it has one, then the rest in order of name.
+pub fn profiles(default_input: &Path) -> Result<Vec<String>> {
+    let (Some(dir), Some(name)) = (default_input.parent(), default_input.file_name()) else {
+        return Ok(vec![]);
+    };
+    let mut others = vec![];
+    for entry in fs::read_dir(dir).with_context(|| format!("Could not list {}", dir.display()))? {
+        let entry = entry?;
+        if has_input(&entry.path().join(name)) {
+            others.push(entry.file_name().to_string_lossy().into_owned());
+        }
+    }
+    others.sort();
+    let default = has_input(default_input).then(|| DEFAULT_PROFILE.to_owned());
+    Ok(default.into_iter().chain(others).collect())
+}
+
+/// Whether an input exists, either as plain text or sealed.
+fn has_input(path: &Path) -> bool {
+    path.is_file() || sealed_path(path).is_file()
+}
+
+/// The answers recorded for each profile and day.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers(BTreeMap<String, BTreeMap<String, Vec<String>>>);
+
+impl Answers {
+    /// Reads the answers file in a year's data directory, if there is one.
+    pub fn load(dir: &Path) -> Result<Answers> {
+        let path = dir.join(ANSWERS_FILE);
+        match fs::read_to_string(&path) {
+            Ok(text) => Answers::parse(&text).with_context(|| format!("In {}", path.display())),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e).with_context(|| format!("Could not read {}", path.display())),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Answers> {
+        Ok(Answers(toml::from_str(text)?))
+    }
+
+    /// The answers recorded for `day` (e.g. `"day08"`) with a profile's input.
+    pub fn get(&self, profile: &str, day: &str) -> Option<&[String]> {
+        Some(self.0.get(profile)?.get(day)?.as_slice())
+    }
+}
+
+/// Picks the answers out of a solution's output: the values from its `problemN = ...` lines.
+pub fn parse_answers(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (name, value) = line.split_once(" = ")?;
+            let part = name.strip_prefix("problem")?;
+            part.parse::<u32>().ok()?;
+            Some(value.trim().to_owned())
+        })
+        .collect()
+}
+
+/// How a profile's answers compare with those recorded for it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Unrecorded,
+    Agrees,
+    Disagrees { recorded: Vec<String> },
+}
+
+pub fn check(answers: &[String], recorded: Option<&[String]>) -> Verdict {
+    match recorded {
+        None => Verdict::Unrecorded,
+        Some(recorded) if recorded == answers => Verdict::Agrees,
+        Some(recorded) => Verdict::Disagrees {
+            recorded: recorded.to_vec(),
+        },
+    }
+}
+
+/// What happened when solving a day with a profile's input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Solved {
+        answers: Vec<String>,
+        verdict: Verdict,
+    },
+    Failed {
+        message: String,
+    },
+}
+
+impl Outcome {
+    /// Whether this needs looking at: the solution failed or disagrees with the recorded answers.
+    pub fn is_flagged(&self) -> bool {
+        !matches!(
+            self,
+            Outcome::Solved {
+                verdict: Verdict::Agrees | Verdict::Unrecorded,
+                ..
+            }
+        )
+    }
+}
+
+/// Writes a table with a row for each profile's outcome. Returns whether any were flagged.
+pub fn write_summary(outcomes: &[(String, Outcome)], out: &mut impl Write) -> io::Result<bool> {
+    let parts = outcomes
+        .iter()
+        .map(|(_, outcome)| match outcome {
+            Outcome::Solved { answers, .. } => answers.len(),
+            Outcome::Failed { .. } => 0,
+        })
+        .max()
+        .unwrap_or(0);
+    let mut rows = vec![];
+    for (profile, outcome) in outcomes {
+        let mut row = vec![profile.clone()];
+        let status = match outcome {
+            Outcome::Solved { answers, verdict } => {
+                row.extend(answers.iter().cloned());
+                match verdict {
+                    Verdict::Agrees => "ok".to_owned(),
+                    Verdict::Unrecorded => "not recorded".to_owned(),
+                    Verdict::Disagrees { recorded } => {
+                        format!("DISAGREES, recorded {}", recorded.join(" / "))
+                    }
+                }
+            }
+            Outcome::Failed { message } => format!("FAILED: {}", message),
+        };
+        row.resize(parts + 1, String::new());
+        row.push(status);
+        rows.push(row);
+    }
+    let header = ["profile".to_owned()]
+        .into_iter()
+        .chain((1..=parts).map(|part| format!("part {}", part)))
+        .chain(["status".to_owned()])
+        .collect::<Vec<String>>();
+    let mut widths = header.iter().map(String::len).collect::<Vec<usize>>();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+    for row in [header].iter().chain(&rows) {
+        let cells = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{:width$}", cell, width = width))
+            .collect::<Vec<String>>();
+        writeln!(out, "{}", cells.join("  ").trim_end())?;
+    }
+    Ok(outcomes.iter().any(|(_, outcome)| outcome.is_flagged()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|&v| v.to_owned()).collect()
+    }
+
+    #[test]
+    fn finds_profiles() {
+        let dir = env::temp_dir().join(format!("aoc-profiles-{}", std::process::id()));
+        fs::create_dir_all(dir.join("carol")).unwrap();
+        fs::create_dir_all(dir.join("bob")).unwrap();
+        fs::create_dir_all(dir.join("alice")).unwrap();
+        fs::create_dir_all(dir.join("dave")).unwrap();
+        for file in [
+            "day08.txt",
+            "bob/day08.txt",
+            "alice/day08.txt",
+            "carol/day09.txt",
+            "dave/day08.txt.enc",
+        ] {
+            fs::write(dir.join(file), "").unwrap();
+        }
+        let found = profiles(&dir.join("day08.txt")).unwrap();
+        assert_eq!(found, strings(&["default", "alice", "bob", "dave"]));
+        let found = profiles(&dir.join("day09.txt")).unwrap();
+        assert_eq!(found, strings(&["carol"]));
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(input_path(2023, 8), Path::new("./data/2023/day08.txt"));
+    }
+
+    #[test]
+    fn checks_answers() {
+        let output = "Moves length 263\nproblem1 = 15517\nproblem2 = 14935034899483\n";
+        let answers = parse_answers(output);
+        assert_eq!(answers, strings(&["15517", "14935034899483"]));
+
+        let recorded = Answers::parse("[alice]\nday08 = [\"15517\", \"1\"]").unwrap();
+        assert_eq!(
+            check(&answers, recorded.get("bob", "day08")),
+            Verdict::Unrecorded
+        );
+        let verdict = check(&answers, recorded.get("alice", "day08"));
+        assert_eq!(
+            verdict,
+            Verdict::Disagrees {
+                recorded: strings(&["15517", "1"])
+            }
+        );
+        assert_eq!(check(&answers, Some(&answers)), Verdict::Agrees);
+        assert!(Answers::parse("[alice]\nday08 = 3").is_err());
+    }
+
+    #[test]
+    fn summarises() {
+        let outcomes = [
+            (
+                "default".to_owned(),
+                Outcome::Solved {
+                    answers: strings(&["6", "7"]),
+                    verdict: Verdict::Agrees,
+                },
+            ),
+            (
+                "alice".to_owned(),
+                Outcome::Solved {
+                    answers: strings(&["123", "4"]),
+                    verdict: Verdict::Disagrees {
+                        recorded: strings(&["123", "5"]),
+                    },
+                },
+            ),
+            (
+                "bob".to_owned(),
+                Outcome::Failed {
+                    message: "No node ZZZ".to_owned(),
+                },
+            ),
+        ];
+        let mut out = vec![];
+        assert!(write_summary(&outcomes, &mut out).unwrap());
+        let expected = "\
+profile  part 1  part 2  status
+default  6       7       ok
+alice    123     4       DISAGREES, recorded 123 / 5
+bob                      FAILED: No node ZZZ
+";
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+
+        let mut out = vec![];
+        assert!(!write_summary(&outcomes[..1], &mut out).unwrap());
+    }
+}
@@ -0,0 +1,94 @@
+//! A small seeded random number generator, for generating puzzle inputs. It's SplitMix64, which
+//! is fast and good enough for test data, and kept here rather than taken from a crate so that a
+//! seed gives the same input whatever the versions of the dependencies.
+
+/// Random numbers from a seed, always the same ones for the same seed.
+#[derive(Clone, Debug)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number less than `n`, which must be positive.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "Nothing is below 0");
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+
+    /// A number from `min` to `max` inclusive.
+    pub fn between(&mut self, min: i64, max: i64) -> i64 {
+        assert!(min <= max, "{} is above {}", min, max);
+        let span = (max as i128 - min as i128 + 1) as u128;
+        let offset = (self.next_u64() as u128 * span) >> 64;
+        (min as i128 + offset as i128) as i64
+    }
+
+    /// True with a chance of `numerator` in `denominator`.
+    pub fn chance(&mut self, numerator: usize, denominator: usize) -> bool {
+        self.below(denominator) < numerator
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+
+    /// `count` different numbers below `n`, in order.
+    pub fn sample(&mut self, n: usize, count: usize) -> Vec<usize> {
+        let mut all = (0..n).collect::<Vec<usize>>();
+        for i in 0..count.min(n) {
+            let j = i + self.below(n - i);
+            all.swap(i, j);
+        }
+        all.truncate(count.min(n));
+        all.sort_unstable();
+        all
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeats_for_a_seed() {
+        let draw = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..20).map(|_| rng.below(1000)).collect::<Vec<usize>>()
+        };
+        assert_eq!(draw(7), draw(7));
+        assert_ne!(draw(7), draw(8));
+
+        let mut rng = Rng::new(1);
+        for _ in 0..1000 {
+            assert!(rng.below(3) < 3);
+            assert!((-2..=2).contains(&rng.between(-2, 2)));
+        }
+        assert_eq!(rng.between(i64::MIN, i64::MIN), i64::MIN);
+        assert!(rng.between(i64::MIN, i64::MAX) != rng.between(i64::MIN, i64::MAX));
+
+        let sample = rng.sample(10, 4);
+        assert_eq!(sample.len(), 4);
+        assert!(sample.windows(2).all(|w| w[0] < w[1]) && sample[3] < 10);
+        assert_eq!(rng.sample(3, 5), [0, 1, 2]);
+        let mut items = [1, 2, 3, 4, 5];
+        rng.shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, [1, 2, 3, 4, 5]);
+    }
+}
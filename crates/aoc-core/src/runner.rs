@@ -0,0 +1,290 @@
+//! Command line options shared by the days' solutions, and the table of days to run.
+
+use crate::assumptions::Assumption;
+use crate::config::load_params;
+use crate::explain::{Explanation, Format};
+use crate::parallel::configure_threads;
+use crate::profiles::DEFAULT_PROFILE;
+use crate::random::Rng;
+use anyhow::{anyhow, bail, Result};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// An event's solutions, one for each day solved.
+#[derive(Copy, Clone, Debug)]
+pub struct Year {
+    pub year: u32,
+    pub days: &'static [Day],
+}
+
+/// A day's solution, which reads the day's input and prints its answers.
+#[derive(Copy, Clone, Debug)]
+pub struct Day {
+    pub number: u32,
+    pub run: fn(&Options) -> Result<()>,
+    /// checks what the solution assumes about the day's input, for days which declare anything
+    pub check: Option<Check>,
+    /// makes up inputs for the day, for days which can
+    pub generate: Option<Generate>,
+    /// named ways of solving each part, for days with more than one to compare
+    pub solvers: &'static [Solver],
+}
+
+/// Checks a day's input against what its solution assumes about it.
+pub type Check = fn(&Options) -> Result<Vec<Assumption>>;
+
+/// One of the ways of solving a part of a day's puzzle, such as a brute force reference or a
+/// faster closed form, which should all give the same answer.
+#[derive(Copy, Clone, Debug)]
+pub struct Solver {
+    pub part: u32,
+    pub name: &'static str,
+    pub solve: fn(&[String]) -> Result<String>,
+}
+
+impl Solver {
+    pub const fn new(
+        part: u32,
+        name: &'static str,
+        solve: fn(&[String]) -> Result<String>,
+    ) -> Self {
+        Solver { part, name, solve }
+    }
+}
+
+/// Makes up a valid input for a day, of a size which means something different for each day.
+pub type Generate = fn(&mut Rng, usize) -> Vec<String>;
+
+impl Day {
+    pub const fn new(number: u32, run: fn(&Options) -> Result<()>) -> Self {
+        Day {
+            number,
+            run,
+            check: None,
+            generate: None,
+            solvers: &[],
+        }
+    }
+
+    pub const fn with_check(self, check: Check) -> Self {
+        Day {
+            check: Some(check),
+            ..self
+        }
+    }
+
+    pub const fn with_generator(self, generate: Generate) -> Self {
+        Day {
+            generate: Some(generate),
+            ..self
+        }
+    }
+
+    pub const fn with_solvers(self, solvers: &'static [Solver]) -> Self {
+        Day { solvers, ..self }
+    }
+}
+
+impl Year {
+    pub const fn new(year: u32, days: &'static [Day]) -> Self {
+        Year { year, days }
+    }
+
+    pub fn day(&self, number: u32) -> Option<&'static Day> {
+        self.days.iter().find(|day| day.number == number)
+    }
+}
+
+/// Finds `year` in a table of years, or the latest year if none is given.
+pub fn find_year(years: &[Year], year: Option<u32>) -> Result<&Year> {
+    match year {
+        Some(year) => years
+            .iter()
+            .find(|y| y.year == year)
+            .ok_or(anyhow!("No solutions for {}", year)),
+        None => years
+            .iter()
+            .max_by_key(|y| y.year)
+            .ok_or(anyhow!("No solutions at all")),
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Options {
+    /// `--year YYYY`: which event's puzzles to solve
+    pub year: Option<u32>,
+    /// `--profile NAME`: whose puzzle input to read
+    pub profile: Option<String>,
+    /// `--all-profiles`: solve every profile's input and compare the answers
+    pub all_profiles: bool,
+    /// `--threads N`: how many threads to spread work across
+    pub threads: Option<usize>,
+    /// `--explain [text|json]`: also describe how the answers were reached, for days which can
+    pub explain: Option<Format>,
+    /// `--size N`: how big an input to generate
+    pub size: Option<usize>,
+    /// `--seed S`: which of the possible inputs to generate
+    pub seed: Option<u64>,
+    /// `--param key=value`, which may be repeated: overrides a puzzle parameter from `aoc.toml`
+    pub params: Vec<String>,
+    /// arguments which aren't options, whose meaning is up to each day and described by its
+    /// `run`, e.g. day 5's seed to explain or day 10's method of counting enclosed tiles
+    pub positional: Vec<String>,
+}
+
+impl Options {
+    /// Applies any options which affect the whole process.
+    pub fn apply(self) -> Result<Self> {
+        if let Some(threads) = self.threads {
+            configure_threads(threads)?;
+        }
+        Ok(self)
+    }
+
+    /// The input file to read, given the day's usual one: for a profile, the file of the same
+    /// name in the profile's directory alongside it.
+    pub fn input_file(&self, default: &str) -> PathBuf {
+        let default = Path::new(default);
+        match (&self.profile, default.parent(), default.file_name()) {
+            (Some(profile), Some(dir), Some(name)) if profile != DEFAULT_PROFILE => {
+                dir.join(profile).join(name)
+            }
+            _ => default.to_owned(),
+        }
+    }
+
+    /// The parameters for `day` (e.g. `"day11"`) of the chosen year, from `aoc.toml` and
+    /// `--param`.
+    pub fn day_params<T: Default + Serialize + DeserializeOwned>(&self, day: &str) -> Result<T> {
+        match self.year {
+            Some(year) => load_params(&format!("{}.{}", year, day), &self.params),
+            None => load_params(day, &self.params),
+        }
+    }
+
+    /// Prints the explanation made by `explain`, if one was asked for with `--explain`.
+    pub fn print_explanation(&self, explain: impl FnOnce() -> Result<Explanation>) -> Result<()> {
+        if let Some(format) = self.explain {
+            explain()?.write(format, &mut io::stdout().lock())?;
+        }
+        Ok(())
+    }
+
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self> {
+        let mut options = Options::default();
+        let mut args = args.into_iter().peekable();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--year" => {
+                    let value = args.next().ok_or(anyhow!("--year needs a value"))?;
+                    options.year = Some(value.parse()?);
+                }
+                "--profile" => {
+                    let value = args.next().ok_or(anyhow!("--profile needs a name"))?;
+                    options.profile = Some(value);
+                }
+                "--all-profiles" => options.all_profiles = true,
+                "--explain" => {
+                    let format = args.next_if(|arg| arg == "text" || arg == "json");
+                    options.explain = Some(format.as_deref().unwrap_or("text").parse()?);
+                }
+                "--threads" => {
+                    let value = args.next().ok_or(anyhow!("--threads needs a value"))?;
+                    options.threads = Some(value.parse()?);
+                }
+                "--size" => {
+                    let value = args.next().ok_or(anyhow!("--size needs a value"))?;
+                    options.size = Some(value.parse()?);
+                }
+                "--seed" => {
+                    let value = args.next().ok_or(anyhow!("--seed needs a value"))?;
+                    options.seed = Some(value.parse()?);
+                }
+                "--param" => {
+                    let value = args.next().ok_or(anyhow!("--param needs key=value"))?;
+                    if !value.contains('=') {
+                        bail!("--param needs key=value, not {}", value);
+                    }
+                    options.params.push(value);
+                }
+                _ if arg.starts_with("--") => bail!("Unknown option {}", arg),
+                _ => options.positional.push(arg),
+            }
+        }
+        Ok(options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Options> {
+        Options::parse(args.iter().map(|&a| a.to_owned()))
+    }
+
+    #[test]
+    fn parses_threads() {
+        let options = parse(&["hands.txt", "--threads", "4"]).unwrap();
+        assert_eq!(options.threads, Some(4));
+        assert_eq!(options.positional, vec!["hands.txt"]);
+        assert!(parse(&["--threads"]).is_err());
+        assert!(parse(&["--threads", "many"]).is_err());
+        assert!(parse(&["--thread", "4"]).is_err());
+    }
+
+    #[test]
+    fn parses_profiles() {
+        let options = parse(&["--profile", "alice"]).unwrap();
+        let input = options.input_file("./data/2023/day08.txt");
+        assert_eq!(input, Path::new("./data/2023/alice/day08.txt"));
+        let options = parse(&["--profile", "default", "--all-profiles"]).unwrap();
+        assert!(options.all_profiles);
+        let input = options.input_file("./data/2023/day08.txt");
+        assert_eq!(input, Path::new("./data/2023/day08.txt"));
+        assert!(parse(&["--profile"]).is_err());
+    }
+
+    #[test]
+    fn finds_years() {
+        static DAYS: [Day; 1] = [Day::new(1, |_| Ok(()))];
+        let years = [Year::new(2022, &[]), Year::new(2023, &DAYS)];
+        let options = parse(&["--year", "2022"]).unwrap();
+        assert_eq!(find_year(&years, options.year).unwrap().year, 2022);
+        assert_eq!(find_year(&years, None).unwrap().year, 2023);
+        assert!(find_year(&years, Some(2015)).is_err());
+        assert!(find_year(&years, None).unwrap().day(1).is_some());
+        assert!(years[0].day(1).is_none());
+        assert!(parse(&["--year", "last"]).is_err());
+    }
+
+    #[test]
+    fn parses_params() {
+        let options = parse(&["--param", "unfold=2", "pair", "--param", "bag.red=20"]).unwrap();
+        assert_eq!(options.params, vec!["unfold=2", "bag.red=20"]);
+        assert_eq!(options.positional, vec!["pair"]);
+        assert!(parse(&["--param"]).is_err());
+        assert!(parse(&["--param", "unfold"]).is_err());
+    }
+
+    #[test]
+    fn parses_explain() {
+        assert_eq!(parse(&[]).unwrap().explain, None);
+        let options = parse(&["--explain", "79"]).unwrap();
+        assert_eq!(options.explain, Some(Format::Text));
+        assert_eq!(options.positional, vec!["79"]);
+        let options = parse(&["79", "--explain", "json"]).unwrap();
+        assert_eq!(options.explain, Some(Format::Json));
+        assert_eq!(options.positional, vec!["79"]);
+    }
+
+    #[test]
+    fn parses_generator_options() {
+        let options = parse(&["--size", "20", "--seed", "7"]).unwrap();
+        assert_eq!((options.size, options.seed), (Some(20), Some(7)));
+        assert!(parse(&["--seed", "-1"]).is_err());
+        assert!(parse(&["--size"]).is_err());
+    }
+}
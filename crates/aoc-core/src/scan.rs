@@ -0,0 +1,289 @@
+//! Declarative extraction of typed fields from a line of input.
+//!
+//! A pattern is literal text with `{name}` placeholders, e.g. `"Game {id}: {draws}"`. Literal
+//! text must match exactly, except that any whitespace in the pattern matches any amount of
+//! whitespace in the input (at least one character if the literal is only whitespace, so that
+//! `"{hand} {bid}"` splits on the gap). Each placeholder captures everything up to the next piece
+//! of literal text (or the end of the line), trimmed of surrounding whitespace, and is converted
+//! with [`FromField`]. The placeholder names are only used in error messages.
+//!
+//! ```
+//! use aoc_core::{parse::Line, scan};
+//!
+//! struct Node<'a> {
+//!     name: &'a str,
+//!     left: &'a str,
+//!     right: &'a str,
+//! }
+//!
+//! let line = Line::new(0, "AAA = (BBB,  CCC)");
+//! let node = scan!(line, "{name} = ({left}, {right})" => Node { name, left, right }).unwrap();
+//! assert_eq!((node.name, node.left, node.right), ("AAA", "BBB", "CCC"));
+//!
+//! let line = Line::new(0, "Card  1: 41 48 83");
+//! let (id, winning): (usize, Vec<usize>) = scan!(line, "Card {id}: {winning}").unwrap();
+//! assert_eq!((id, winning), (1, vec![41, 48, 83]));
+//! ```
+
+use crate::parse::{Line, ParseError};
+use std::str::FromStr;
+
+/// Scans `fragment` (a slice of `line`'s text), or the whole line, against a pattern.
+///
+/// The result can be any tuple of [`FromField`] types with one element per placeholder, or a
+/// struct built from such a tuple with the `=> Type { field, ... }` form.
+#[macro_export]
+macro_rules! scan {
+    ($line:expr, $fragment:expr, $pattern:literal => $ty:ident { $($field:ident),+ $(,)? }) => {
+        $crate::scan::scan(&$line, $fragment, $pattern).map(|($($field,)+)| $ty { $($field),+ })
+    };
+    ($line:expr, $pattern:literal => $ty:ident { $($field:ident),+ $(,)? }) => {
+        $crate::scan::scan(&$line, $line.text, $pattern).map(|($($field,)+)| $ty { $($field),+ })
+    };
+    ($line:expr, $fragment:expr, $pattern:literal) => {
+        $crate::scan::scan(&$line, $fragment, $pattern)
+    };
+    ($line:expr, $pattern:literal) => {
+        $crate::scan::scan(&$line, $line.text, $pattern)
+    };
+}
+
+pub fn scan<'a, T: FromFields<'a>>(
+    line: &Line<'a>,
+    fragment: &'a str,
+    pattern: &str,
+) -> Result<T, ParseError> {
+    let fields = scan_fields(line, fragment, pattern)?;
+    T::from_fields(line, &fields)
+}
+
+/// Matches `fragment` against `pattern`, returning each placeholder's name and captured text.
+pub fn scan_fields<'a, 'p>(
+    line: &Line<'a>,
+    fragment: &'a str,
+    pattern: &'p str,
+) -> Result<Vec<(&'p str, &'a str)>, ParseError> {
+    let mut fields = vec![];
+    let mut pending_field = None;
+    let mut rest = fragment.trim();
+    for part in parse_pattern(pattern) {
+        match part {
+            Part::Field(name) => {
+                assert!(
+                    pending_field.is_none(),
+                    "Pattern {:?} has adjacent fields",
+                    pattern
+                );
+                pending_field = Some(name);
+            }
+            Part::Literal(literal) => {
+                let not_found = || line.error(rest, &format!("{:?}", literal));
+                let (start, len) = match pending_field.take() {
+                    Some(name) => {
+                        let (start, len) = rest
+                            .char_indices()
+                            .find_map(|(i, _)| Some((i, match_literal(&rest[i..], literal)?)))
+                            .ok_or_else(not_found)?;
+                        fields.push((name, rest[..start].trim()));
+                        (start, len)
+                    }
+                    None => (0, match_literal(rest, literal).ok_or_else(not_found)?),
+                };
+                rest = &rest[start + len..];
+            }
+        }
+    }
+    match pending_field {
+        Some(name) => fields.push((name, rest.trim())),
+        None if !rest.trim().is_empty() => return Err(line.error(rest.trim(), "end of line")),
+        None => {}
+    }
+    Ok(fields)
+}
+
+enum Part<'p> {
+    Literal(&'p str),
+    Field(&'p str),
+}
+
+fn parse_pattern(pattern: &str) -> Vec<Part<'_>> {
+    let mut parts = vec![];
+    let mut rest = pattern;
+    while let Some(open) = rest.find('{') {
+        let close = rest[open..]
+            .find('}')
+            .unwrap_or_else(|| panic!("Unclosed field in pattern {:?}", pattern));
+        if open > 0 {
+            parts.push(Part::Literal(&rest[..open]));
+        }
+        parts.push(Part::Field(&rest[open + 1..open + close]));
+        rest = &rest[open + close + 1..];
+    }
+    if !rest.is_empty() {
+        parts.push(Part::Literal(rest));
+    }
+    parts
+}
+
+/// Length of the prefix of `text` matched by `literal`, if it matches.
+fn match_literal(text: &str, literal: &str) -> Option<usize> {
+    let min_whitespace = if literal.trim().is_empty() { 1 } else { 0 };
+    let mut pos = 0;
+    let mut in_whitespace = false;
+    for c in literal.chars() {
+        if c.is_whitespace() {
+            if !in_whitespace {
+                let rest = &text[pos..];
+                let len = rest.len() - rest.trim_start().len();
+                if len < min_whitespace {
+                    return None;
+                }
+                pos += len;
+            }
+            in_whitespace = true;
+        } else {
+            in_whitespace = false;
+            let t = text[pos..].chars().next()?;
+            if t != c {
+                return None;
+            }
+            pos += t.len_utf8();
+        }
+    }
+    Some(pos)
+}
+
+/// A type that can be converted from the text captured by one placeholder.
+pub trait FromField<'a>: Sized {
+    fn from_field(line: &Line<'a>, name: &str, text: &'a str) -> Result<Self, ParseError>;
+}
+
+impl<'a> FromField<'a> for &'a str {
+    fn from_field(_: &Line<'a>, _: &str, text: &'a str) -> Result<Self, ParseError> {
+        Ok(text)
+    }
+}
+
+impl<'a> FromField<'a> for String {
+    fn from_field(_: &Line<'a>, _: &str, text: &'a str) -> Result<Self, ParseError> {
+        Ok(text.to_owned())
+    }
+}
+
+macro_rules! number_from_field {
+    ($($t:ty),+) => {
+        $(
+            impl<'a> FromField<'a> for $t {
+                fn from_field(line: &Line<'a>, name: &str, text: &'a str) -> Result<Self, ParseError> {
+                    line.parse(text, &format!("a number for {}", name))
+                }
+            }
+        )+
+    };
+}
+
+number_from_field!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// A list of values separated by whitespace and/or commas.
+impl<'a, T: FromStr> FromField<'a> for Vec<T> {
+    fn from_field(line: &Line<'a>, name: &str, text: &'a str) -> Result<Self, ParseError> {
+        text.split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|item| !item.is_empty())
+            .map(|item| line.parse(item, &format!("a list item for {}", name)))
+            .collect()
+    }
+}
+
+/// A fixed length list of values separated by whitespace and/or commas.
+impl<'a, T: FromStr, const N: usize> FromField<'a> for [T; N] {
+    fn from_field(line: &Line<'a>, name: &str, text: &'a str) -> Result<Self, ParseError> {
+        Vec::<T>::from_field(line, name, text)?
+            .try_into()
+            .map_err(|_| line.error(text, &format!("{} values for {}", N, name)))
+    }
+}
+
+/// A tuple of [`FromField`] values, one per placeholder of a pattern.
+pub trait FromFields<'a>: Sized {
+    fn from_fields(line: &Line<'a>, fields: &[(&str, &'a str)]) -> Result<Self, ParseError>;
+}
+
+macro_rules! tuple_from_fields {
+    ($($t:ident),+) => {
+        impl<'a, $($t: FromField<'a>),+> FromFields<'a> for ($($t,)+) {
+            fn from_fields(line: &Line<'a>, fields: &[(&str, &'a str)]) -> Result<Self, ParseError> {
+                let arity = [$(stringify!($t)),+].len();
+                assert_eq!(fields.len(), arity, "Pattern has {} fields but {} were expected", fields.len(), arity);
+                let mut fields = fields.iter();
+                Ok(($({
+                    let &(name, text) = fields.next().unwrap();
+                    $t::from_field(line, name, text)?
+                },)+))
+            }
+        }
+    };
+}
+
+tuple_from_fields!(A);
+tuple_from_fields!(A, B);
+tuple_from_fields!(A, B, C);
+tuple_from_fields!(A, B, C, D);
+tuple_from_fields!(A, B, C, D, E);
+tuple_from_fields!(A, B, C, D, E, F);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tolerates_whitespace() {
+        let line = Line::new(0, "Card   3:1 21 53 |69 82  1");
+        let (id, winning, have): (usize, Vec<usize>, Vec<usize>) =
+            scan!(line, "Card {id}: {winning} | {have}").unwrap();
+        assert_eq!(id, 3);
+        assert_eq!(winning, vec![1, 21, 53]);
+        assert_eq!(have, vec![69, 82, 1]);
+    }
+
+    #[test]
+    fn fragment_and_arrays() {
+        let line = Line::new(0, "19, 13, 30 @ -2,  1, -2");
+        let (p, v): ([isize; 3], [isize; 3]) = scan!(line, "{position} @ {velocity}").unwrap();
+        assert_eq!((p, v), ([19, 13, 30], [-2, 1, -2]));
+
+        let (x,): (isize,) = scan!(line, &line.text[4..6], "{x}").unwrap();
+        assert_eq!(x, 13);
+    }
+
+    #[test]
+    fn locates_errors() {
+        let line = Line::new(1, "Game 7; 3 blue");
+        let error = scan!(line, "Game {id}: {draws}")
+            .map(|(_, _): (usize, &str)| ())
+            .unwrap_err();
+        assert_eq!((error.line, error.column), (2, 6));
+        assert_eq!(error.expected, "\": \"");
+
+        let line = Line::new(0, "Game x: 3 blue");
+        let error = scan!(line, "Game {id}: {draws}")
+            .map(|(_, _): (usize, &str)| ())
+            .unwrap_err();
+        assert_eq!(
+            (error.column, error.expected.as_str()),
+            (6, "a number for id")
+        );
+
+        let line = Line::new(0, "32T3K 765 extra");
+        let error = scan!(line, "{hand} {bid}")
+            .map(|(_, _): (&str, usize)| ())
+            .unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (7, "765 extra"));
+
+        let line = Line::new(0, "Game 1: 3 blue");
+        let error = scan!(line, "Game {id}:")
+            .map(|(_,): (usize,)| ())
+            .unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (9, "3 blue"));
+        assert_eq!(error.expected, "end of line");
+    }
+}
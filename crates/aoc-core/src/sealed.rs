@@ -0,0 +1,278 @@
+//! Puzzle inputs encrypted at rest, so that they can be committed without publishing them. Each
+//! `dayNN.txt` is sealed into `dayNN.txt.enc` with AES-256-GCM, under a key kept outside the
+//! repository: in the `AOC_KEY` environment variable, or else the `.aoc-key` file. Inputs are
+//! only ever decrypted in memory, by [`crate::input::open`], unless explicitly unsealed.
+//!
+//! Each input is sealed under its path within `data`, e.g. `2023/alice/day05.txt`, so a sealed
+//! file copied to another year, profile or day won't unseal.
+
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use aes_gcm::{Aes256Gcm, Nonce};
+use anyhow::{anyhow, bail, ensure, Context, Result};
+use std::ffi::OsStr;
+use std::fs::{self, OpenOptions};
+use std::io::{self, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+pub const KEY_VAR: &str = "AOC_KEY";
+pub const KEY_FILE: &str = ".aoc-key";
+/// The directory inputs are kept in, which their sealed names are relative to.
+pub const DATA_DIR: &str = "data";
+
+/// Starts every sealed file, so that other files are rejected clearly.
+const MAGIC: &[u8] = b"aoc-sealed-1\n";
+const NONCE_LEN: usize = 12;
+
+/// Writes a new key file, readable only by its owner.
+fn write_key_file(text: &str) -> io::Result<()> {
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    options.open(KEY_FILE)?.write_all(text.as_bytes())
+}
+
+/// The sealed file for a plaintext file, e.g. `day05.txt.enc` for `day05.txt`.
+pub fn sealed_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_owned();
+    name.push(".enc");
+    path.with_file_name(name)
+}
+
+/// The key inputs are sealed with.
+#[derive(Clone)]
+pub struct Key(Aes256Gcm);
+
+impl Key {
+    pub fn generate() -> (Key, String) {
+        let key = Aes256Gcm::generate_key(OsRng);
+        (Key(Aes256Gcm::new(&key)), to_hex(&key))
+    }
+
+    /// Reads a key written as 64 hex digits.
+    pub fn from_hex(text: &str) -> Result<Key> {
+        let bytes = from_hex(text.trim()).ok_or(anyhow!("A key should be 64 hex digits"))?;
+        let cipher = Aes256Gcm::new_from_slice(&bytes).map_err(|_| anyhow!("A key is 32 bytes"))?;
+        Ok(Key(cipher))
+    }
+
+    /// The key from `AOC_KEY`, or else from `.aoc-key`.
+    pub fn load() -> Result<Key> {
+        if let Ok(text) = std::env::var(KEY_VAR) {
+            return Key::from_hex(&text).with_context(|| format!("Bad key in {}", KEY_VAR));
+        }
+        match fs::read_to_string(KEY_FILE) {
+            Ok(text) => Key::from_hex(&text).with_context(|| format!("Bad key in {}", KEY_FILE)),
+            Err(e) if e.kind() == ErrorKind::NotFound => bail!(
+                "No key to unseal inputs with: set {} or save it in {}",
+                KEY_VAR,
+                KEY_FILE
+            ),
+            Err(e) => Err(e).with_context(|| format!("Could not read {}", KEY_FILE)),
+        }
+    }
+
+    /// Like [`Key::load`], but if there is no key yet, makes one and saves it in `.aoc-key`.
+    pub fn load_or_create() -> Result<Key> {
+        if std::env::var_os(KEY_VAR).is_some() || Path::new(KEY_FILE).exists() {
+            return Key::load();
+        }
+        let (key, hex) = Key::generate();
+        write_key_file(&(hex + "\n")).with_context(|| format!("Could not write {}", KEY_FILE))?;
+        eprintln!(
+            "Created a new key in {}; keep it safe, it isn't committed",
+            KEY_FILE
+        );
+        Ok(key)
+    }
+
+    /// Encrypts an input. Its name is authenticated too, so that one day's sealed input can't be
+    /// mistaken for another's.
+    pub fn seal(&self, name: &str, plaintext: &[u8]) -> Vec<u8> {
+        let nonce = Aes256Gcm::generate_nonce(OsRng);
+        let payload = Payload {
+            msg: plaintext,
+            aad: name.as_bytes(),
+        };
+        let ciphertext = self
+            .0
+            .encrypt(&nonce, payload)
+            .expect("Inputs fit in memory");
+        [MAGIC, nonce.as_slice(), &ciphertext].concat()
+    }
+
+    pub fn unseal(&self, name: &str, sealed: &[u8]) -> Result<Vec<u8>> {
+        let rest = sealed
+            .strip_prefix(MAGIC)
+            .ok_or(anyhow!("Not a sealed input"))?;
+        ensure!(rest.len() >= NONCE_LEN, "Sealed input is truncated");
+        let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+        let payload = Payload {
+            msg: ciphertext,
+            aad: name.as_bytes(),
+        };
+        self.0
+            .decrypt(Nonce::from_slice(nonce), payload)
+            .map_err(|_| {
+                anyhow!(
+                    "Could not unseal {}: wrong key, or the file is damaged",
+                    name
+                )
+            })
+    }
+}
+
+/// Reads and decrypts a sealed file, e.g. `data/2023/day05.txt.enc`.
+pub fn read_sealed(path: &Path, key: &Key) -> Result<Vec<u8>> {
+    let name = input_name(&path.with_file_name(plaintext_name(path)?))?;
+    let sealed = fs::read(path).with_context(|| format!("Could not read {}", path.display()))?;
+    key.unseal(&name, &sealed)
+}
+
+/// The name an input is sealed under: its path within the nearest directory called `data`,
+/// e.g. `2023/alice/day05.txt` for `data/2023/alice/day05.txt`.
+fn input_name(path: &Path) -> Result<String> {
+    let components = path
+        .components()
+        .map(|c| c.as_os_str().to_str())
+        .collect::<Option<Vec<&str>>>()
+        .ok_or(anyhow!("{} isn't a UTF-8 path", path.display()))?;
+    let data = components
+        .iter()
+        .rposition(|&c| c == DATA_DIR)
+        .ok_or(anyhow!(
+            "{} isn't in a {} directory",
+            path.display(),
+            DATA_DIR
+        ))?;
+    Ok(components[data + 1..].join("/"))
+}
+
+/// The name of the input a sealed file holds, e.g. `day05.txt` for `day05.txt.enc`.
+fn plaintext_name(sealed: &Path) -> Result<String> {
+    sealed
+        .file_name()
+        .and_then(OsStr::to_str)
+        .and_then(|name| name.strip_suffix(".enc"))
+        .map(str::to_owned)
+        .ok_or(anyhow!("{} isn't a sealed input", sealed.display()))
+}
+
+/// Seals every `.txt` file under `dir`, which should be or be within a `data` directory,
+/// leaving the plaintext in place. Returns the files written.
+pub fn seal_dir(dir: &Path, key: &Key) -> Result<Vec<PathBuf>> {
+    let mut sealed = vec![];
+    for path in files(dir, "txt")? {
+        let plaintext = fs::read(&path)?;
+        let target = sealed_path(&path);
+        fs::write(&target, key.seal(&input_name(&path)?, &plaintext))?;
+        sealed.push(target);
+    }
+    Ok(sealed)
+}
+
+/// Decrypts every `.txt.enc` file under `dir` back into a `.txt` file. Returns the files written.
+pub fn unseal_dir(dir: &Path, key: &Key) -> Result<Vec<PathBuf>> {
+    let mut unsealed = vec![];
+    for path in files(dir, "enc")? {
+        let target = path.with_file_name(plaintext_name(&path)?);
+        fs::write(&target, read_sealed(&path, key)?)?;
+        unsealed.push(target);
+    }
+    Ok(unsealed)
+}
+
+/// Every file under `dir` with the given extension, in order.
+fn files(dir: &Path, extension: &str) -> Result<Vec<PathBuf>> {
+    let mut found = vec![];
+    let mut to_visit = vec![dir.to_owned()];
+    while let Some(dir) = to_visit.pop() {
+        let entries =
+            fs::read_dir(&dir).with_context(|| format!("Could not list {}", dir.display()))?;
+        for entry in entries {
+            let path = entry?.path();
+            if path.is_dir() {
+                to_visit.push(path);
+            } else if path.extension() == Some(OsStr::new(extension)) {
+                found.push(path);
+            }
+        }
+    }
+    found.sort();
+    Ok(found)
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn from_hex(text: &str) -> Option<Vec<u8>> {
+    if !text.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..text.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(text.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn seals_and_unseals() {
+        let (key, hex) = Key::generate();
+        assert_eq!(hex.len(), 64);
+        let sealed = key.seal("2023/day05.txt", b"seeds: 79 14 55 13\n");
+        assert!(!sealed.windows(5).any(|w| w == b"seeds"));
+        let key = Key::from_hex(&hex).unwrap();
+        assert_eq!(
+            key.unseal("2023/day05.txt", &sealed).unwrap(),
+            b"seeds: 79 14 55 13\n"
+        );
+
+        assert!(key.unseal("2023/day06.txt", &sealed).is_err());
+        assert!(key.unseal("2023/alice/day05.txt", &sealed).is_err());
+        assert!(key.unseal("2024/day05.txt", &sealed).is_err());
+        let (other_key, _) = Key::generate();
+        assert!(other_key.unseal("2023/day05.txt", &sealed).is_err());
+        let mut damaged = sealed.clone();
+        *damaged.last_mut().unwrap() ^= 1;
+        assert!(key.unseal("2023/day05.txt", &damaged).is_err());
+        assert!(key.unseal("2023/day05.txt", b"seeds: 79").is_err());
+        assert!(Key::from_hex("abc").is_err());
+        assert!(Key::from_hex(&"0".repeat(62)).is_err());
+    }
+
+    #[test]
+    fn seals_directories() {
+        let root = env::temp_dir().join(format!("aoc-sealed-{}", std::process::id()));
+        let dir = root.join("data/2023");
+        fs::create_dir_all(dir.join("alice")).unwrap();
+        fs::write(dir.join("day01.txt"), "1abc2\n").unwrap();
+        fs::write(dir.join("alice/day01.txt"), "a1b2c3\n").unwrap();
+        fs::write(dir.join("answers.toml"), "").unwrap();
+        let (key, _) = Key::generate();
+
+        let sealed = seal_dir(&dir, &key).unwrap();
+        assert_eq!(
+            sealed,
+            [dir.join("alice/day01.txt.enc"), dir.join("day01.txt.enc")]
+        );
+        fs::remove_file(dir.join("alice/day01.txt")).unwrap();
+        let unsealed = unseal_dir(&dir, &key).unwrap();
+        assert_eq!(unsealed.len(), 2);
+        let text = fs::read_to_string(dir.join("alice/day01.txt")).unwrap();
+        assert_eq!(text, "a1b2c3\n");
+        let alice = read_sealed(&dir.join("alice/day01.txt.enc"), &key).unwrap();
+        assert_eq!(alice, b"a1b2c3\n");
+
+        // sealed for the default profile, so it won't pass for alice's
+        fs::copy(dir.join("day01.txt.enc"), dir.join("alice/day01.txt.enc")).unwrap();
+        assert!(read_sealed(&dir.join("alice/day01.txt.enc"), &key).is_err());
+        assert!(read_sealed(&root.join("day01.txt.enc"), &key).is_err());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,64 @@
+//! Searching graphs whose nodes and edges are worked out as they are reached.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// Every node which can be reached from `start`, including `start` itself. This keeps its own
+/// stack rather than recursing, as [`Bounds::walk`](crate::grid::Bounds::walk) loops.
+pub fn reachable<N, I>(start: N, mut neighbours: impl FnMut(N) -> I) -> HashSet<N>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::from([start]);
+    let mut to_visit = vec![start];
+    while let Some(node) = to_visit.pop() {
+        for next in neighbours(node) {
+            if seen.insert(next) {
+                to_visit.push(next);
+            }
+        }
+    }
+    seen
+}
+
+/// The number of steps from `start` to every node which can be reached from it, breadth first.
+pub fn distances<N, I>(start: N, mut neighbours: impl FnMut(N) -> I) -> HashMap<N, usize>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::from([(start, 0)]);
+    let mut to_visit = VecDeque::from([start]);
+    while let Some(node) = to_visit.pop_front() {
+        let distance = distances[&node] + 1;
+        for next in neighbours(node) {
+            distances.entry(next).or_insert_with(|| {
+                to_visit.push_back(next);
+                distance
+            });
+        }
+    }
+    distances
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn searches_number_line() {
+        let steps = |n: i32| [n - 3, n + 2].into_iter().filter(|n| (0..10).contains(n));
+        assert_eq!(reachable(0, steps).len(), 10);
+        assert_eq!(
+            reachable(0, |n: i32| [n + 2].into_iter().filter(|&n| n < 10)).len(),
+            5
+        );
+
+        let distances = distances(0, steps);
+        assert_eq!(distances[&0], 0);
+        assert_eq!(distances[&1], 3);
+        assert_eq!(distances[&9], 7);
+        assert_eq!(distances.len(), 10);
+    }
+}
@@ -1,24 +1,62 @@
 #!/bin/zsh
 
+# Usage: new_day.sh [day [year]], for today by default, or December of the given year.
+
 if [[ -z $1 ]] then
   DATE=$(date -j +'%Y-%m-%d')
-else
+elif [[ -z $2 ]] then
   DATE=$(date -j -f '%d' $1 +'%Y-%m-%d')
+else
+  DATE=$(date -j -f '%Y-%m-%d' "$2-12-$1" +'%Y-%m-%d')
 fi
 
 echo "Checking date $DATE"
 
+YEAR=$(date -j -f '%Y-%m-%d' $DATE +'%Y')
+DAY=$(date -j -f '%Y-%m-%d' $DATE +'%-d')
 FILENAME="day$(date -j -f '%Y-%m-%d' $DATE +'%d')"
 
-RS_FILE="src/bin/$FILENAME.rs"
+CRATE=crates/aoc-$YEAR
+SOLUTIONS=$CRATE/src
+if [[ ! -d $CRATE ]]; then
+    echo "creating $CRATE for $YEAR's solutions"
+    mkdir -p $SOLUTIONS
+    sed "s/2023/$YEAR/g" crates/aoc-2023/Cargo.toml > $CRATE/Cargo.toml
+    sed "s/2023/$YEAR/g" crates/aoc-2023/src/day_template.rs > $SOLUTIONS/day_template.rs
+    cat > $SOLUTIONS/lib.rs <<RUST
+//! Solutions to [Advent of Code $YEAR](https://adventofcode.com/$YEAR), a module per day.
+
+use aoc_core::runner::{Day, Year};
+
+/// Copied by \`new_day.sh\` to start each new day.
+#[doc(hidden)]
+pub mod day_template;
+
+pub static YEAR: Year = Year::new($YEAR, DAYS);
+
+pub static DAYS: &[Day] = &[
+    // new_day.sh adds each new day here
+];
+RUST
+    echo "adding $CRATE to the workspace and the aoc binary"
+    perl -0pi -e "s#(\naoc-\d+ = .*\n)(?!aoc-\d+ = )#\$1aoc-$YEAR = { path = \"$CRATE\" }\n#" Cargo.toml
+    perl -0pi -e "s/(\naoc-\d+\.workspace = true\n)(?!aoc-\d+\.workspace)/\$1aoc-$YEAR.workspace = true\n/; s/(parallel = \[.*)\]/\$1, \"aoc-$YEAR\/parallel\"]/" crates/aoc-cli/Cargo.toml
+    perl -0pi -e "s/(static YEARS: &\[Year\] = &\[.*)\];/\$1, aoc_$YEAR::YEAR];/" crates/aoc-cli/src/main.rs
+fi
+
+RS_FILE="$SOLUTIONS/$FILENAME.rs"
 if [[ -f $RS_FILE ]]; then
     echo "$RS_FILE already exists"
 else
     echo "creating $RS_FILE"
-    cp src/bin/day_template.rs $RS_FILE
+    sed "s/dayNN/$FILENAME/" $SOLUTIONS/day_template.rs > $RS_FILE
+    echo "adding $FILENAME to $SOLUTIONS/lib.rs"
+    perl -0pi -e "s/(pub mod day\d+;\n)(?!pub mod day)/\$1pub mod $FILENAME;\n/ or s/\n\npub static YEAR/\n\npub mod $FILENAME;\n\npub static YEAR/; s/(\n *\/\/ new_day.sh adds)/\n    Day::new($DAY, $FILENAME::run),\$1/" $SOLUTIONS/lib.rs
+    cargo fmt --all
 fi
 
-TXT_FILE="data/$FILENAME.txt"
+TXT_FILE="data/$YEAR/$FILENAME.txt"
+mkdir -p data/$YEAR
 if [[ -f $TXT_FILE ]]; then
     echo "$TXT_FILE already exists"
 elif [[ -z $AOC_COOKIE ]]; then
@@ -26,5 +64,5 @@ elif [[ -z $AOC_COOKIE ]]; then
     touch $TXT_FILE
 else
     echo "downloading $TXT_FILE"
-    curl -H "cookie: $AOC_COOKIE" "https://adventofcode.com/$(date -j -f '%Y-%m-%d' $DATE +'%Y')/day/$(date -j -f '%Y-%m-%d' $DATE +'%-d')/input" -o $TXT_FILE
+    curl -H "cookie: $AOC_COOKIE" "https://adventofcode.com/$YEAR/day/$DAY/input" -o $TXT_FILE
 fi